@@ -0,0 +1,170 @@
+use advent_of_code_2023::{parse::ParseError, Solution, Variant};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+// Each sample runs the benchmarked function enough times to take at least this long
+const MIN_SAMPLE_TIME: Duration = Duration::from_millis(1);
+
+/// Timing statistics of a single benchmarked function, in nanoseconds per call
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Stats {
+    pub mean: f64,
+    pub median: f64,
+    pub stddev: f64,
+    pub min: f64,
+    pub max: f64,
+    pub samples: usize,
+    pub iterations_per_sample: u32,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<f64>, iterations_per_sample: u32) -> Stats {
+        samples.sort_unstable_by(f64::total_cmp);
+
+        let count = samples.len() as f64;
+        let mean = samples.iter().sum::<f64>() / count;
+        let variance = samples
+            .iter()
+            .map(|sample| (sample - mean).powi(2))
+            .sum::<f64>()
+            / count;
+
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2.0
+        } else {
+            samples[middle]
+        };
+
+        Stats {
+            mean,
+            median,
+            stddev: variance.sqrt(),
+            min: samples[0],
+            max: samples[samples.len() - 1],
+            samples: samples.len(),
+            iterations_per_sample,
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:>10} ± {:>9} (median {}, {} samples)",
+            format_nanos(self.mean),
+            format_nanos(self.stddev),
+            format_nanos(self.median),
+            self.samples
+        )
+    }
+}
+
+/// Formats a duration in nanoseconds with a unit that keeps the number readable
+pub fn format_nanos(nanos: f64) -> String {
+    match nanos {
+        nanos if nanos < 1_000.0 => format!("{nanos:.1}ns"),
+        nanos if nanos < 1_000_000.0 => format!("{:.1}us", nanos / 1_000.0),
+        nanos if nanos < 1_000_000_000.0 => format!("{:.1}ms", nanos / 1_000_000.0),
+        nanos => format!("{:.2}s", nanos / 1_000_000_000.0),
+    }
+}
+
+/// Benchmark results of parsing and solving both parts of a single day
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DayBench {
+    pub day: u8,
+    pub parse: Stats,
+    pub part_1: Stats,
+    pub part_2: Stats,
+}
+
+/// Measures `function` over `samples` samples, batching calls so fast functions can be timed
+fn measure<T>(samples: usize, mut function: impl FnMut() -> T) -> Stats {
+    // Warm up, and estimate how many calls fit in one sample
+    let start = Instant::now();
+    black_box(function());
+    let estimate = start.elapsed().max(Duration::from_nanos(1));
+
+    let iterations_per_sample =
+        (MIN_SAMPLE_TIME.as_nanos() / estimate.as_nanos()).clamp(1, 1_000_000) as u32;
+
+    let samples = (0..samples.max(1))
+        .map(|_| {
+            let start = Instant::now();
+
+            for _ in 0..iterations_per_sample {
+                black_box(function());
+            }
+
+            start.elapsed().as_nanos() as f64 / iterations_per_sample as f64
+        })
+        .collect();
+
+    Stats::from_samples(samples, iterations_per_sample)
+}
+
+/// Benchmarks parsing and each part separately, the parts are measured on an already parsed input
+pub fn bench_day<D: Solution>(
+    day: u8,
+    input: &str,
+    samples: usize,
+) -> Result<DayBench, ParseError> {
+    let parsed = D::parse(input)?;
+
+    let parse = measure(samples, || D::parse(black_box(input)));
+    let part_1 = measure(samples, || D::part_1(black_box(&parsed)));
+    let part_2 = measure(samples, || D::part_2(black_box(&parsed)));
+
+    Ok(DayBench {
+        day,
+        parse,
+        part_1,
+        part_2,
+    })
+}
+
+/// Timing of one of several implementations of the same part
+#[derive(Debug, Clone)]
+pub struct VariantBench {
+    pub name: String,
+    pub stats: Stats,
+}
+
+/// Benchmarks each of a day's alternative implementations of its parts
+pub fn bench_variants(variants: Vec<Variant>, samples: usize) -> Vec<VariantBench> {
+    variants
+        .into_iter()
+        .map(|(name, function)| VariantBench {
+            name,
+            stats: measure(samples, function),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(vec![4.0, 2.0, 8.0, 6.0], 1);
+
+        assert_eq!(stats.mean, 5.0);
+        assert_eq!(stats.median, 5.0);
+        assert_eq!(stats.stddev, 5.0f64.sqrt());
+        assert_eq!((stats.min, stats.max), (2.0, 8.0));
+    }
+
+    #[test]
+    fn test_format_nanos() {
+        assert_eq!(format_nanos(12.34), "12.3ns");
+        assert_eq!(format_nanos(16_396_000.0), "16.4ms");
+        assert_eq!(format_nanos(100.0 * 1_000.0), "100.0us");
+    }
+}
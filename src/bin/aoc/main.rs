@@ -0,0 +1,364 @@
+mod bench;
+mod readme;
+
+use advent_of_code_2023::{parse::ParseError, *};
+use anyhow::{anyhow, bail, Context};
+use bench::{DayBench, VariantBench};
+use clap::{Parser, Subcommand};
+use readme::{Regression, Timing};
+use std::{
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Answers to each solved part, by part number
+type Answers = Vec<(u8, String)>;
+
+/// Renders a day's puzzle state for the terminal
+type Visualize = fn(&str) -> Result<String, ParseError>;
+
+/// Explains how a day's answer to a single part was reached
+type Explain = fn(&str, u8) -> Result<String, ParseError>;
+
+/// Alternative implementations of a day's parts, to benchmark against each other
+type BenchVariants = fn(&str) -> Result<Vec<Variant<'_>>, ParseError>;
+
+/// Type-erased entry points into a single day's [`Solution`]
+struct Day {
+    input: &'static str,
+    solve: fn(&str, Option<u8>) -> Result<Answers, ParseError>,
+    bench: fn(u8, &str, usize) -> Result<DayBench, ParseError>,
+    visualize: Option<Visualize>,
+    explain: Option<Explain>,
+    bench_variants: Option<BenchVariants>,
+}
+
+impl Day {
+    const fn new<D: Solution>() -> Day {
+        Day {
+            input: D::INPUT,
+            solve: solve::<D>,
+            bench: bench::bench_day::<D>,
+            visualize: None,
+            explain: None,
+            bench_variants: None,
+        }
+    }
+
+    const fn with_visualize(self, visualize: Visualize) -> Day {
+        Day {
+            visualize: Some(visualize),
+            ..self
+        }
+    }
+
+    const fn with_explain(self, explain: Explain) -> Day {
+        Day {
+            explain: Some(explain),
+            ..self
+        }
+    }
+
+    const fn with_bench_variants(self, bench_variants: BenchVariants) -> Day {
+        Day {
+            bench_variants: Some(bench_variants),
+            ..self
+        }
+    }
+}
+
+const DAYS: [Day; 11] = [
+    Day::new::<day01::Day01>(),
+    Day::new::<day02::Day02>(),
+    Day::new::<day03::Day03>(),
+    Day::new::<day04::Day04>(),
+    Day::new::<day05::Day05>(),
+    Day::new::<day06::Day06>(),
+    Day::new::<day07::Day07>()
+        .with_explain(day07::explain)
+        .with_bench_variants(day07::variants),
+    Day::new::<day08::Day08>(),
+    Day::new::<day09::Day09>(),
+    Day::new::<day10::Day10>().with_visualize(day10::visualize),
+    Day::new::<day11::Day11>(),
+];
+
+/// Parses the input once and solves the requested parts, or both if none is given
+fn solve<D: Solution>(input: &str, part: Option<u8>) -> Result<Answers, ParseError> {
+    let parsed = D::parse(input)?;
+
+    let mut answers = Vec::new();
+
+    if part.is_none_or(|part| part == 1) {
+        answers.push((1, D::part_1(&parsed).to_string()));
+    }
+
+    if part.is_none_or(|part| part == 2) {
+        answers.push((2, D::part_2(&parsed).to_string()));
+    }
+
+    Ok(answers)
+}
+
+#[derive(Parser)]
+#[command(about = "Advent of code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solutions for a single day, or every day in sequence
+    Run {
+        /// Day to run, runs all days if omitted
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=DAYS.len() as i64))]
+        day: Option<u8>,
+
+        /// Only run a single part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Read the puzzle input from this file instead of the bundled one, `-` reads from stdin
+        #[arg(long, requires = "day")]
+        input: Option<PathBuf>,
+
+        /// Render the puzzle state in the terminal before the answers, if the day supports it
+        #[arg(long, requires = "day")]
+        visualize: bool,
+
+        /// Explain how the answer to each part was reached, if the day supports it
+        #[arg(long, requires = "day")]
+        explain: bool,
+    },
+
+    /// Benchmark parsing and each part of a single day, or of every day
+    Bench {
+        /// Day to benchmark, benchmarks all days if omitted
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=DAYS.len() as i64))]
+        day: Option<u8>,
+
+        /// Number of timing samples to take of each function
+        #[arg(long, default_value_t = 100)]
+        samples: usize,
+
+        /// Print the results as JSON instead of a table
+        #[arg(long)]
+        json: bool,
+
+        /// Also benchmark alternative implementations of the parts, for days that have them
+        #[arg(long, conflicts_with = "json")]
+        variants: bool,
+    },
+
+    /// Benchmark every day and rewrite the timing table in the README with the results
+    Readme {
+        /// Only compare against the recorded timings, failing if any part got slower
+        #[arg(long)]
+        check: bool,
+
+        /// How many percent slower than the recorded time a part may get before --check fails
+        #[arg(long, default_value_t = 25.0)]
+        threshold: f64,
+
+        /// Number of timing samples to take of each function
+        #[arg(long, default_value_t = 100)]
+        samples: usize,
+
+        #[arg(long, default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/README.md"))]
+        readme: PathBuf,
+    },
+}
+
+fn read_input(path: &Path) -> anyhow::Result<String> {
+    if path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .context("Failed to read input from stdin")?;
+
+        return Ok(input);
+    }
+
+    fs::read_to_string(path)
+        .with_context(|| format!("Failed to read input from {}", path.display()))
+}
+
+fn run_day(
+    number: u8,
+    part: Option<u8>,
+    input: Option<&str>,
+    visualize: bool,
+    explain: bool,
+) -> anyhow::Result<()> {
+    let day = &DAYS[number as usize - 1];
+    let input = input.unwrap_or(day.input);
+
+    if visualize {
+        let visualize = day
+            .visualize
+            .with_context(|| format!("Day {number:02} has no visualization"))?;
+
+        let output = visualize(input).map_err(|error| parse_error(number, input, error))?;
+        println!("{output}");
+    }
+
+    if explain {
+        let explain = day
+            .explain
+            .with_context(|| format!("Day {number:02} has no explanation"))?;
+
+        for part_number in [1, 2] {
+            if part.is_none_or(|part| part == part_number) {
+                let output = explain(input, part_number)
+                    .map_err(|error| parse_error(number, input, error))?;
+                println!("Day {number:02} part {part_number}:\n{output}\n");
+            }
+        }
+    }
+
+    let answers = (day.solve)(input, part).map_err(|error| parse_error(number, input, error))?;
+
+    for (part_number, answer) in answers {
+        println!("Day {number:02} part {part_number}: {answer}");
+    }
+
+    Ok(())
+}
+
+fn parse_error(number: u8, input: &str, error: ParseError) -> anyhow::Error {
+    anyhow!(
+        "Failed to parse input for day {number:02} at {}",
+        error.diagnostic(input)
+    )
+}
+
+fn bench_day(number: u8, samples: usize) -> anyhow::Result<DayBench> {
+    let day = &DAYS[number as usize - 1];
+
+    (day.bench)(number, day.input, samples).map_err(|error| parse_error(number, day.input, error))
+}
+
+fn bench_days(days: Vec<u8>, samples: usize, json: bool, variants: bool) -> anyhow::Result<()> {
+    let mut results = Vec::new();
+
+    for number in days {
+        let result = bench_day(number, samples)?;
+
+        if !json {
+            println!("Day {number:02} parse  {}", result.parse);
+            println!("       part 1 {}", result.part_1);
+            println!("       part 2 {}", result.part_2);
+        }
+
+        let day = &DAYS[number as usize - 1];
+
+        if let (true, Some(bench_variants)) = (variants, day.bench_variants) {
+            let variants =
+                bench_variants(day.input).map_err(|error| parse_error(number, day.input, error))?;
+            let variants = bench::bench_variants(variants, samples);
+
+            let width = variants.iter().map(|variant| variant.name.len()).max();
+
+            for VariantBench { name, stats } in variants {
+                println!("       {name:width$} {stats}", width = width.unwrap_or(0));
+            }
+        }
+
+        results.push(result);
+    }
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&results)?);
+    }
+
+    Ok(())
+}
+
+fn update_readme(path: &Path, samples: usize, check: bool, threshold: f64) -> anyhow::Result<()> {
+    let readme = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read README from {}", path.display()))?;
+
+    let timings = (1..=DAYS.len() as u8)
+        .map(|number| bench_day(number, samples).map(|bench| Timing::from_bench(&bench)))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    if !check {
+        fs::write(path, readme::replace_table(&readme, &timings)?)
+            .with_context(|| format!("Failed to write README to {}", path.display()))?;
+
+        print!("{}", readme::render_table(&timings));
+        return Ok(());
+    }
+
+    let recorded = readme::parse_table(&readme)?;
+    let regressions = readme::find_regressions(&recorded, &timings, threshold);
+
+    for Regression {
+        day,
+        part,
+        recorded,
+        current,
+    } in &regressions
+    {
+        println!("Day {day:02} part {part}: {current:.1}us, recorded {recorded:.1}us");
+    }
+
+    if !regressions.is_empty() {
+        bail!(
+            "{} parts are more than {threshold}% slower than recorded in the README",
+            regressions.len()
+        );
+    }
+
+    println!("No part is more than {threshold}% slower than recorded in the README");
+
+    Ok(())
+}
+
+fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            visualize,
+            explain,
+        } => {
+            let input = input.as_deref().map(read_input).transpose()?;
+
+            match day {
+                Some(day) => run_day(day, part, input.as_deref(), visualize, explain)?,
+                None => {
+                    for day in 1..=DAYS.len() as u8 {
+                        run_day(day, part, None, false, false)?;
+                    }
+                }
+            }
+        }
+        Command::Bench {
+            day,
+            samples,
+            json,
+            variants,
+        } => {
+            let days = match day {
+                Some(day) => vec![day],
+                None => (1..=DAYS.len() as u8).collect(),
+            };
+
+            bench_days(days, samples, json, variants)?;
+        }
+        Command::Readme {
+            check,
+            threshold,
+            samples,
+            readme,
+        } => update_readme(&readme, samples, check, threshold)?,
+    }
+
+    Ok(())
+}
@@ -0,0 +1,246 @@
+use crate::bench::DayBench;
+use anyhow::{bail, Context};
+
+const TABLE_HEADER: &str = "| Day";
+
+/// Time in microseconds to parse the input and solve each part of a day, as listed in the README
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Timing {
+    pub day: u8,
+    pub part_1: f64,
+    pub part_2: f64,
+}
+
+impl Timing {
+    /// Uses the median, since it is less affected by outliers than the mean
+    pub fn from_bench(bench: &DayBench) -> Timing {
+        Timing {
+            day: bench.day,
+            part_1: (bench.parse.median + bench.part_1.median) / 1_000.0,
+            part_2: (bench.parse.median + bench.part_2.median) / 1_000.0,
+        }
+    }
+}
+
+/// A part that got slower than its recorded time by more than the allowed threshold
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Regression {
+    pub day: u8,
+    pub part: u8,
+    pub recorded: f64,
+    pub current: f64,
+}
+
+fn format_micros(micros: f64) -> String {
+    if micros < 1.0 {
+        format!("{micros:.1}us")
+    } else {
+        format!("{micros:.0}us")
+    }
+}
+
+fn parse_micros(time: &str) -> Option<f64> {
+    time.trim().strip_suffix("us")?.parse().ok()
+}
+
+pub fn render_table(timings: &[Timing]) -> String {
+    let rows = timings
+        .iter()
+        .map(|timing| {
+            [
+                format!("[Day {0:02}](src/day{0:02}/mod.rs)", timing.day),
+                format_micros(timing.part_1),
+                format_micros(timing.part_2),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let header = ["Day", "Part 1", "Part 2"].map(String::from);
+
+    let widths: [usize; 3] = std::array::from_fn(|column| {
+        rows.iter()
+            .chain([&header])
+            .map(|row| row[column].len())
+            .max()
+            .unwrap_or(0)
+    });
+
+    let render_row = |row: &[String; 3]| {
+        let cells = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!(" {cell:width$} "))
+            .collect::<Vec<_>>();
+
+        format!("|{}|\n", cells.join("|"))
+    };
+
+    let separator = widths
+        .map(|width| format!(" {} ", "-".repeat(width)))
+        .join("|");
+
+    let mut table = render_row(&header);
+    table.push_str(&format!("|{separator}|\n"));
+
+    for row in &rows {
+        table.push_str(&render_row(row));
+    }
+
+    table
+}
+
+/// Finds the byte range of the timing table, including the trailing newline of its last row
+fn find_table(readme: &str) -> anyhow::Result<(usize, usize)> {
+    let start = readme
+        .find(TABLE_HEADER)
+        .context("README does not contain a timing table")?;
+
+    let end = readme[start..]
+        .split_inclusive('\n')
+        .take_while(|line| line.starts_with('|'))
+        .map(str::len)
+        .sum::<usize>();
+
+    Ok((start, start + end))
+}
+
+pub fn parse_table(readme: &str) -> anyhow::Result<Vec<Timing>> {
+    let (start, end) = find_table(readme)?;
+
+    readme[start..end]
+        .lines()
+        .skip(2) // Skip header and separator
+        .map(|row| {
+            let cells = row.trim_matches('|').split('|').collect::<Vec<_>>();
+
+            let [day, part_1, part_2] = cells[..] else {
+                bail!("Expected 3 columns in README table row: {row}");
+            };
+
+            let day = day
+                .split_once("Day ")
+                .and_then(|(_, day)| day.get(..2)?.parse().ok())
+                .with_context(|| format!("Invalid day in README table row: {row}"))?;
+
+            let [part_1, part_2] = [part_1, part_2].map(parse_micros);
+            let (Some(part_1), Some(part_2)) = (part_1, part_2) else {
+                bail!("Invalid time in README table row: {row}");
+            };
+
+            Ok(Timing {
+                day,
+                part_1,
+                part_2,
+            })
+        })
+        .collect()
+}
+
+pub fn replace_table(readme: &str, timings: &[Timing]) -> anyhow::Result<String> {
+    let (start, end) = find_table(readme)?;
+
+    Ok(format!(
+        "{}{}{}",
+        &readme[..start],
+        render_table(timings),
+        &readme[end..]
+    ))
+}
+
+/// Compares the current timings against the recorded ones, `threshold` is in percent
+pub fn find_regressions(
+    recorded: &[Timing],
+    current: &[Timing],
+    threshold: f64,
+) -> Vec<Regression> {
+    let allowed = 1.0 + threshold / 100.0;
+
+    current
+        .iter()
+        .filter_map(|current| {
+            let recorded = recorded
+                .iter()
+                .find(|recorded| recorded.day == current.day)?;
+
+            Some([
+                (current.day, 1, recorded.part_1, current.part_1),
+                (current.day, 2, recorded.part_2, current.part_2),
+            ])
+        })
+        .flatten()
+        .filter(|&(_, _, recorded, current)| current > recorded * allowed)
+        .map(|(day, part, recorded, current)| Regression {
+            day,
+            part,
+            recorded,
+            current,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const README: &str = "# Title\n\n| Day | Part 1 | Part 2 |\n| --- | ------ | ------ |\n| [Day 01](src/day01/mod.rs) | 12us | 36us |\n| [Day 06](src/day06/mod.rs) | 0.1us | 16396us |\n\nFooter\n";
+
+    #[test]
+    fn test_parse_table() {
+        let timings = parse_table(README).unwrap();
+
+        assert_eq!(
+            timings,
+            [
+                Timing {
+                    day: 1,
+                    part_1: 12.0,
+                    part_2: 36.0
+                },
+                Timing {
+                    day: 6,
+                    part_1: 0.1,
+                    part_2: 16396.0
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_replace_table() {
+        let timings = [Timing {
+            day: 6,
+            part_1: 0.14,
+            part_2: 1.6,
+        }];
+
+        let readme = replace_table(README, &timings).unwrap();
+
+        assert_eq!(
+            readme,
+            "# Title\n\n| Day                        | Part 1 | Part 2 |\n| -------------------------- | ------ | ------ |\n| [Day 06](src/day06/mod.rs) | 0.1us  | 2us    |\n\nFooter\n"
+        );
+        assert_eq!(parse_table(&readme).unwrap()[0].day, 6);
+    }
+
+    #[test]
+    fn test_find_regressions() {
+        let recorded = parse_table(README).unwrap();
+        let current = [Timing {
+            day: 1,
+            part_1: 13.0,
+            part_2: 40.0,
+        }];
+
+        let regressions = find_regressions(&recorded, &current, 10.0);
+
+        assert_eq!(
+            regressions,
+            [Regression {
+                day: 1,
+                part: 2,
+                recorded: 36.0,
+                current: 40.0
+            }]
+        );
+    }
+}
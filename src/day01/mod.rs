@@ -0,0 +1,190 @@
+use crate::{
+    parse::{ParseError, Source},
+    Solution,
+};
+
+pub struct Day01;
+
+struct CalibrationDigits {
+    first_digit: u8,
+    last_digit: u8,
+}
+
+fn parse_part1<'a>(lines: &'a [&[u8]]) -> impl Iterator<Item = CalibrationDigits> + 'a {
+    // Lines with only spelled out digits have no calibration value in part 1
+    lines.iter().filter_map(|line| {
+        fn find_first_digit(mut line: impl Iterator<Item = u8>) -> Option<u8> {
+            line.find(|&char| char <= b'9').map(|char| char - b'0')
+        }
+
+        let first_digit = find_first_digit(line.iter().copied())?;
+        let last_digit = find_first_digit(line.iter().copied().rev())?;
+
+        Some(CalibrationDigits {
+            first_digit,
+            last_digit,
+        })
+    })
+}
+
+fn find_word_digit(word: &[u8]) -> Option<u8> {
+    const WORD_DIGITS: [(&[u8], u8); 9] = [
+        (b"one", 1),
+        (b"two", 2),
+        (b"three", 3),
+        (b"four", 4),
+        (b"five", 5),
+        (b"six", 6),
+        (b"seven", 7),
+        (b"eight", 8),
+        (b"nine", 9),
+    ];
+
+    let length = word.len();
+
+    for (target_word, digit) in WORD_DIGITS {
+        if length < target_word.len() {
+            continue;
+        }
+
+        let is_match = target_word
+            .iter()
+            .zip(word.iter())
+            .all(|(&target_char, &char)| target_char == char);
+
+        if is_match {
+            return Some(digit);
+        }
+    }
+
+    None
+}
+
+fn parse_part2<'a>(lines: &'a [&[u8]]) -> impl Iterator<Item = CalibrationDigits> + 'a {
+    // Lines without any digit, spelled out or not, have no calibration value
+    lines.iter().filter_map(|line| {
+        let find_digit_at_index = |index: usize| {
+            let digit_char = line[index];
+
+            if digit_char <= b'9' {
+                return Some(digit_char - b'0');
+            }
+
+            if let Some(word_digit) = find_word_digit(&line[index..]) {
+                return Some(word_digit);
+            }
+
+            None
+        };
+
+        let first_digit = (0..line.len()).find_map(find_digit_at_index)?;
+        let last_digit = (0..line.len()).rev().find_map(find_digit_at_index)?;
+
+        Some(CalibrationDigits {
+            first_digit,
+            last_digit,
+        })
+    })
+}
+
+fn calculate(digits: impl Iterator<Item = CalibrationDigits>) -> u64 {
+    digits
+        .map(
+            |CalibrationDigits {
+                 first_digit,
+                 last_digit,
+             }| first_digit * 10 + last_digit,
+        )
+        .map(|number| number as u64)
+        .sum()
+}
+
+impl Solution for Day01 {
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Parsed<'a> = Vec<&'a [u8]>;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let source = Source(input);
+
+        let is_valid = |char: u8| char.is_ascii_digit() | char.is_ascii_lowercase();
+
+        // Checking every byte without branching or stopping early is a lot faster, lines only need
+        // to be searched when something else than a line ending is invalid
+        let all_valid = input.bytes().fold(true, |all_valid, char| {
+            all_valid & (is_valid(char) | (char == b'\n'))
+        });
+
+        input
+            .lines()
+            .map(|line| {
+                // Everything before the first invalid byte is ASCII, so it starts a character
+                let invalid_char = match all_valid {
+                    true => None,
+                    false => line.bytes().position(|char| !is_valid(char)),
+                };
+
+                if let Some(index) = invalid_char {
+                    return Err(source.error(&line[index..], "a digit or a lowercase letter"));
+                }
+
+                Ok(line.as_bytes())
+            })
+            .collect()
+    }
+
+    fn part_1(lines: &Self::Parsed<'_>) -> u64 {
+        calculate(parse_part1(lines))
+    }
+
+    fn part_2(lines: &Self::Parsed<'_>) -> u64 {
+        calculate(parse_part2(lines))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let result = Day01::solve_part_1(Day01::INPUT).unwrap();
+        assert_eq!(result, 54601);
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = Day01::solve_part_2(Day01::INPUT).unwrap();
+        assert_eq!(result, 54078);
+    }
+
+    #[test]
+    fn test_example_part1() {
+        let result = Day01::solve_part_1(include_str!("example_1.txt")).unwrap();
+        assert_eq!(result, 142);
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let result = Day01::solve_part_2(include_str!("example_2.txt")).unwrap();
+        assert_eq!(result, 281);
+    }
+
+    #[test]
+    fn test_overlapping_words() {
+        assert_eq!(Day01::solve_part_2("eightwo").unwrap(), 82);
+        assert_eq!(Day01::solve_part_2("oneight").unwrap(), 18);
+        assert_eq!(Day01::solve_part_2("twone").unwrap(), 21);
+        assert_eq!(Day01::solve_part_2("xtwone3four").unwrap(), 24);
+        assert_eq!(Day01::solve_part_2("sevenine2eighthree").unwrap(), 73);
+    }
+
+    #[test]
+    fn test_lines_without_digits() {
+        let input = "abc\n1x2\nxyz\nnine";
+
+        assert_eq!(Day01::solve_part_1(input).unwrap(), 12);
+        assert_eq!(Day01::solve_part_2(input).unwrap(), 12 + 99);
+    }
+}
@@ -0,0 +1,178 @@
+use crate::{
+    parse::{ParseError, Source},
+    Solution,
+};
+
+pub struct Day02;
+
+#[derive(Debug, Clone, Copy)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+impl Color {
+    fn max_amount(self) -> u32 {
+        match self {
+            Color::Red => 12,
+            Color::Green => 13,
+            Color::Blue => 14,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Cube {
+    color: Color,
+    amount: u32,
+}
+
+#[derive(Debug)]
+pub struct Game {
+    id: u32,
+    // Cubes of every revealed set, neither part needs to know which set they were in
+    revealed_cubes: Vec<Cube>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct MaxByColor {
+    red: u32,
+    green: u32,
+    blue: u32,
+}
+
+impl MaxByColor {
+    pub fn add_cube(mut self, cube: Cube) -> MaxByColor {
+        let value = match cube.color {
+            Color::Red => &mut self.red,
+            Color::Green => &mut self.green,
+            Color::Blue => &mut self.blue,
+        };
+
+        if cube.amount > *value {
+            *value = cube.amount;
+        }
+
+        self
+    }
+
+    pub fn power(self) -> u32 {
+        self.red * self.green * self.blue
+    }
+}
+
+fn parse_game<'a>(source: Source<'a>, line: &'a str) -> Result<Game, ParseError> {
+    let (left_side, right_side) = source.split_once(line, ": ")?;
+
+    let id = source.strip_prefix(left_side, "Game ")?;
+    let id = source.parse::<u32>(id, "a game id")?;
+
+    // Splitting on single characters is a lot faster than on "; " and ", ", which leaves the space
+    // after the separator at the start of every cube but the first
+    let revealed_cubes = right_side
+        .split([';', ','])
+        .enumerate()
+        .map(|(index, revealed_cube)| {
+            let revealed_cube = match index {
+                0 => revealed_cube,
+                _ => source.strip_prefix(revealed_cube, " ")?,
+            };
+
+            let (amount, color) = source.split_once(revealed_cube, " ")?;
+
+            let color = match color {
+                "red" => Color::Red,
+                "green" => Color::Green,
+                "blue" => Color::Blue,
+                _ => return Err(source.error(color, "red, green or blue")),
+            };
+
+            let amount = source.parse::<u32>(amount, "an amount of cubes")?;
+
+            Ok(Cube { color, amount })
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Game { id, revealed_cubes })
+}
+
+impl Solution for Day02 {
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Parsed<'a> = Vec<Game>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let source = Source(input);
+
+        input.lines().map(|line| parse_game(source, line)).collect()
+    }
+
+    fn part_1(games: &Self::Parsed<'_>) -> u32 {
+        games
+            .iter()
+            .filter_map(|game| {
+                let is_valid: bool = game
+                    .revealed_cubes
+                    .iter()
+                    .all(|revealed_cube| revealed_cube.amount <= revealed_cube.color.max_amount());
+
+                is_valid.then_some(game.id)
+            })
+            .sum()
+    }
+
+    fn part_2(games: &Self::Parsed<'_>) -> u32 {
+        games
+            .iter()
+            .map(|game| {
+                game.revealed_cubes
+                    .iter()
+                    .fold(MaxByColor::default(), |max_by_color, &cube| {
+                        max_by_color.add_cube(cube)
+                    })
+                    .power()
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(Day02::solve_part_1(Day02::INPUT).unwrap(), 2377);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(Day02::solve_part_2(Day02::INPUT).unwrap(), 71220);
+    }
+
+    #[test]
+    fn test_example_part_1() {
+        assert_eq!(Day02::solve_part_1(include_str!("example.txt")).unwrap(), 8);
+    }
+
+    #[test]
+    fn test_example_part_2() {
+        assert_eq!(
+            Day02::solve_part_2(include_str!("example.txt")).unwrap(),
+            2286
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Day02::parse("Game 1: 3 blue;4 red").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 16));
+        assert_eq!(error.expected, "\" \"");
+
+        let error = Day02::parse("Game 1: 3 blue, 4 pink").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 19));
+        assert_eq!(error.expected, "red, green or blue");
+    }
+}
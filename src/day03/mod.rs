@@ -0,0 +1,197 @@
+use crate::{grid::Grid, parse::ParseError, Solution};
+use vek::Vec2;
+
+pub struct Day03;
+
+#[derive(Debug)]
+struct EngineNumber {
+    number: i32,
+    start_position: Vec2<usize>,
+    horizontal_length: usize,
+}
+
+pub struct ParseResult {
+    // The engine schematic, one byte per tile
+    schematic: Grid<u8>,
+
+    // All positions of gears
+    gear_positions: Vec<Vec2<usize>>,
+
+    // All engine numbers
+    engine_numbers: Vec<EngineNumber>,
+
+    // Index of the engine number covering each tile, if any
+    engine_number_positions: Grid<Option<usize>>,
+}
+
+fn is_symbol(tile: u8) -> bool {
+    tile != b'.' && !tile.is_ascii_digit()
+}
+
+fn parse_input(input: &str) -> Result<ParseResult, ParseError> {
+    let schematic = Grid::parse(input, |_, char| {
+        if char.is_ascii_graphic() {
+            Ok(char as u8)
+        } else {
+            Err("a digit, '.' or a symbol")
+        }
+    })?;
+
+    let gear_positions = schematic
+        .iter()
+        .filter_map(|(position, &tile)| (tile == b'*').then_some(position))
+        .collect();
+
+    let mut engine_numbers = Vec::<EngineNumber>::new();
+    let mut engine_number_positions = Grid::from_elem(schematic.size(), None);
+
+    for (y, row) in schematic.rows().enumerate() {
+        let mut characters = row.iter().copied().enumerate().peekable();
+
+        while let Some((x, char)) = characters.next() {
+            if !char.is_ascii_digit() {
+                continue;
+            }
+
+            let mut sum = (char - b'0') as i32;
+            let mut horizontal_length = 1;
+
+            while let Some((_, next_digit)) = characters.next_if(|(_, char)| char.is_ascii_digit())
+            {
+                sum = (sum * 10) + (next_digit - b'0') as i32;
+                horizontal_length += 1;
+            }
+
+            engine_numbers.push(EngineNumber {
+                number: sum,
+                start_position: Vec2::new(x, y),
+                horizontal_length,
+            });
+
+            let index = engine_numbers.len() - 1;
+
+            for x_offset in 0..horizontal_length {
+                engine_number_positions[Vec2::new(x + x_offset, y)] = Some(index);
+            }
+        }
+    }
+
+    Ok(ParseResult {
+        schematic,
+        gear_positions,
+        engine_numbers,
+        engine_number_positions,
+    })
+}
+
+impl Solution for Day03 {
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Parsed<'a> = ParseResult;
+    type Answer = i32;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(parsed: &Self::Parsed<'_>) -> i32 {
+        let ParseResult {
+            schematic,
+            engine_numbers,
+            ..
+        } = parsed;
+
+        let mut sum_of_part_numbers = 0;
+
+        for EngineNumber {
+            number,
+            start_position,
+            horizontal_length,
+        } in engine_numbers
+        {
+            let start_position = start_position.as_::<i32>();
+            let horizontal_length = *horizontal_length as i32;
+
+            for y in (start_position.y - 1)..=(start_position.y + 1) {
+                for x in (start_position.x - 1)..(start_position.x + horizontal_length + 1) {
+                    if y == start_position.y
+                        && x >= start_position.x
+                        && x < (start_position.x + horizontal_length)
+                    {
+                        continue;
+                    }
+
+                    let position = Vec2::new(x, y);
+                    if schematic.get(position).is_some_and(|&tile| is_symbol(tile)) {
+                        sum_of_part_numbers += number;
+                    }
+                }
+            }
+        }
+
+        sum_of_part_numbers
+    }
+
+    fn part_2(parsed: &Self::Parsed<'_>) -> i32 {
+        let ParseResult {
+            gear_positions,
+            engine_numbers,
+            engine_number_positions,
+            ..
+        } = parsed;
+
+        let gear_position_neighbors = gear_positions.iter().map(|&gear_position| {
+            let mut neighbors = Vec::new();
+
+            for target_position in engine_number_positions.neighbors_8(gear_position) {
+                if let Some(engine_number_index) = engine_number_positions[target_position] {
+                    if !neighbors.contains(&engine_number_index) {
+                        neighbors.push(engine_number_index);
+                    }
+                }
+            }
+
+            neighbors
+        });
+
+        let gear_ratios = gear_position_neighbors
+            .filter(|neighbors| neighbors.len() == 2)
+            .map(|neighbors| {
+                neighbors
+                    .into_iter()
+                    .map(|engine_number_index| engine_numbers[engine_number_index].number)
+                    .product::<i32>()
+            });
+
+        gear_ratios.sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        let result = Day03::solve_part_1(Day03::INPUT).unwrap();
+        assert_eq!(result, 544433);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let result = Day03::solve_part_2(Day03::INPUT).unwrap();
+        assert_eq!(result, 76314915);
+    }
+
+    #[test]
+    fn test_example_part_1() {
+        let result = Day03::solve_part_1(include_str!("example.txt")).unwrap();
+        assert_eq!(result, 4361);
+    }
+
+    #[test]
+    fn test_example_part_2() {
+        let result = Day03::solve_part_2(include_str!("example.txt")).unwrap();
+        assert_eq!(result, 467835);
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{
+    parse::{ParseError, Source},
+    Solution,
+};
+use std::ops::Range;
+
+pub struct Day04;
+
+#[derive(Debug)]
+struct Card {
+    // Set of the winning numbers, where bit n is set when n is one of them
+    winning_numbers: u128,
+    // Where the numbers I have for this card are in `Cards::my_numbers`
+    my_numbers: Range<usize>,
+}
+
+#[derive(Debug)]
+pub struct Cards {
+    cards: Vec<Card>,
+    // Numbers I have for every card one after another, which saves an allocation per card
+    my_numbers: Vec<u8>,
+}
+
+impl Cards {
+    /// Amount of winning numbers that I have for every card
+    fn wins(&self) -> impl Iterator<Item = usize> + '_ {
+        self.cards.iter().map(|card| {
+            self.my_numbers[card.my_numbers.clone()]
+                .iter()
+                .filter(|&&number| card.winning_numbers & (1 << number) != 0)
+                .count()
+        })
+    }
+}
+
+fn parse_number(source: Source<'_>, number: &str) -> Result<u8, ParseError> {
+    match number.parse::<u8>() {
+        Ok(number @ 0..128) => Ok(number),
+        _ => Err(source.error(number, "a number below 128")),
+    }
+}
+
+fn parse_cards(input: &str) -> Result<Cards, ParseError> {
+    let source = Source(input);
+    let mut cards = Vec::new();
+    let mut my_numbers = Vec::new();
+
+    for line in input.lines() {
+        let (_left_part, right_part) = source.split_once(line, ": ")?;
+
+        let (winning_numbers, numbers) = source.split_once(right_part, " | ")?;
+
+        let mut card = Card {
+            winning_numbers: 0,
+            my_numbers: my_numbers.len()..my_numbers.len(),
+        };
+
+        for number in winning_numbers.split_ascii_whitespace() {
+            card.winning_numbers |= 1 << parse_number(source, number)?;
+        }
+
+        for number in numbers.split_ascii_whitespace() {
+            my_numbers.push(parse_number(source, number)?);
+        }
+
+        card.my_numbers.end = my_numbers.len();
+        cards.push(card);
+    }
+
+    Ok(Cards { cards, my_numbers })
+}
+
+impl Solution for Day04 {
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Parsed<'a> = Cards;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_cards(input)
+    }
+
+    fn part_1(cards: &Self::Parsed<'_>) -> u64 {
+        cards
+            .wins()
+            .map(|wins| match wins {
+                0 => 0,
+                wins => 1 << (wins - 1),
+            })
+            .sum()
+    }
+
+    fn part_2(cards: &Self::Parsed<'_>) -> u64 {
+        let mut amount_of_copies = Vec::new();
+
+        for (index, card_wins) in cards.wins().enumerate() {
+            let copies = amount_of_copies.get(index).copied().unwrap_or_else(|| {
+                amount_of_copies.push(1);
+                1
+            });
+
+            for index_offset in 1..=card_wins {
+                let target_index = index + index_offset;
+
+                if target_index >= amount_of_copies.len() {
+                    amount_of_copies.push(copies + 1)
+                } else {
+                    amount_of_copies[target_index] += copies;
+                }
+            }
+        }
+
+        amount_of_copies.into_iter().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        let part_1 = Day04::solve_part_1(Day04::INPUT).unwrap();
+        assert_eq!(part_1, 26426);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let part_2 = Day04::solve_part_2(Day04::INPUT).unwrap();
+        assert_eq!(part_2, 6227972);
+    }
+
+    #[test]
+    fn test_example_part_1() {
+        let part_1 = Day04::solve_part_1(include_str!("example.txt")).unwrap();
+        assert_eq!(part_1, 13);
+    }
+
+    #[test]
+    fn test_example_part_2() {
+        let part_2 = Day04::solve_part_2(include_str!("example.txt")).unwrap();
+        assert_eq!(part_2, 30);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Day04::parse("Card 1: 41 48 | 83 86\nCard 2: 13 32 | 61 x").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 20));
+        assert_eq!(error.expected, "a number below 128");
+
+        let error = Day04::parse("Card 1: 41 128 | 83 86").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 12));
+
+        let error = Day04::parse("Card 1: 41 48 83 86").unwrap_err();
+        assert_eq!(error.expected, "\" | \"");
+    }
+}
@@ -0,0 +1,747 @@
+use crate::{
+    interval_set::IntervalSet,
+    parse::{ParseError, Source},
+    RangeExt, Solution,
+};
+use itertools::Itertools;
+use rangemap::RangeMap;
+use std::{collections::HashMap, error::Error, fmt, ops::Range};
+
+pub struct Day05;
+
+// Every number a map can be asked about
+const ALL_NUMBERS: Range<i64> = i64::MIN..i64::MAX;
+
+// Categories the puzzle goes from and to
+const SEED: &str = "seed";
+const LOCATION: &str = "location";
+
+/// Moves numbers by the offset of the rule they fall in, numbers outside every rule stay the same
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Map {
+    // Map from source range to destination offset
+    rules: RangeMap<i64, i64>,
+}
+
+impl Map {
+    pub fn find_destination_number(&self, source_number: i64) -> i64 {
+        let offset = self.rules.get(&source_number);
+
+        offset
+            .map(|offset| source_number + offset) // Apply offset if a mapping exists
+            .unwrap_or(source_number) // Otherwise source number
+    }
+
+    /// Moves every number of `range`, parts of it outside every rule stay where they are
+    pub fn find_destination_ranges<'a>(
+        &'a self,
+        range: &'a Range<i64>,
+    ) -> impl Iterator<Item = Range<i64>> + 'a {
+        self.pieces(range).map(|(source_range, offset)| {
+            (source_range.start + offset)..(source_range.end + offset)
+        })
+    }
+
+    /// Finds every range of numbers this map moves into `range`, the inverse of
+    /// [`Map::find_destination_ranges`]
+    ///
+    /// Rules can move numbers onto numbers that stay where they are, so a number can have several
+    /// sources.
+    pub fn find_source_ranges<'a>(
+        &'a self,
+        range: &'a Range<i64>,
+    ) -> impl Iterator<Item = Range<i64>> + 'a {
+        self.pieces(&ALL_NUMBERS)
+            .filter_map(move |(source_range, offset)| {
+                let destination_range = (source_range.start + offset)..(source_range.end + offset);
+                let overlap = destination_range.overlap(range)?;
+
+                Some((overlap.start - offset)..(overlap.end - offset))
+            })
+    }
+
+    /// Splits `range` into parts that are each covered by a single rule or by none, together with
+    /// the offset every number of the part moves by
+    fn pieces<'a>(&'a self, range: &'a Range<i64>) -> impl Iterator<Item = (Range<i64>, i64)> + 'a {
+        let mut rules = self.rules.overlapping(range).peekable();
+        let mut start = range.start;
+
+        // Walks the rules once, filling the gaps before and between them with parts that stay
+        std::iter::from_fn(move || {
+            if start >= range.end {
+                return None;
+            }
+
+            let piece = match rules.peek() {
+                Some((source_range, _)) if source_range.start <= start => {
+                    let (source_range, &offset) = rules.next()?;
+
+                    (start..source_range.end.min(range.end), offset)
+                }
+                next_rule => {
+                    let end = next_rule.map_or(range.end, |(source_range, _)| source_range.start);
+
+                    (start..end, 0)
+                }
+            };
+
+            start = piece.0.end;
+
+            Some(piece)
+        })
+    }
+
+    /// Map that moves every number like applying this map and then `next` does
+    pub fn compose(&self, next: &Map) -> Map {
+        let mut rules = RangeMap::new();
+
+        for (range, offset) in self.pieces(&ALL_NUMBERS) {
+            let destination_range = (range.start + offset)..(range.end + offset);
+
+            for (next_range, next_offset) in next.pieces(&destination_range) {
+                let total_offset = offset + next_offset;
+
+                // Numbers that end up where they started don't need a rule
+                if total_offset != 0 {
+                    let source_range = (next_range.start - offset)..(next_range.end - offset);
+                    rules.insert(source_range, total_offset);
+                }
+            }
+        }
+
+        Map { rules }
+    }
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, (source_range, offset)) in self.rules.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+
+            write!(f, "{source_range:?} {offset:+}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Map from one category to another, labelled like `seed-to-soil` in the almanac
+#[derive(Debug, Clone)]
+pub struct NamedMap<'a> {
+    pub name: &'a str,
+    pub source: &'a str,
+    pub destination: &'a str,
+    pub map: Map,
+}
+
+/// Reason the maps don't lead from one category to another
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChainError<'a> {
+    /// No map goes from this category
+    Missing(&'a str),
+    /// More than one map goes from this category
+    Ambiguous(&'a str),
+    /// The maps go around in circles after reaching this category
+    Cycle(&'a str),
+}
+
+impl fmt::Display for ChainError<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChainError::Missing(category) => write!(f, "no map from {category}"),
+            ChainError::Ambiguous(category) => write!(f, "more than one map from {category}"),
+            ChainError::Cycle(category) => write!(f, "maps go around in circles at {category}"),
+        }
+    }
+}
+
+impl Error for ChainError<'_> {}
+
+fn parse_map<'a>(source: Source<'a>, input: &'a str) -> Result<NamedMap<'a>, ParseError> {
+    let mut lines = input.lines();
+
+    let label = lines.next().unwrap_or(input);
+    let name = source.strip_suffix(label, " map:")?;
+    let (source_category, destination_category) = source.split_once(name, "-to-")?;
+
+    let rules = lines
+        .map(|line| {
+            let Some((destination_start, source_start, length)) =
+                source.parse_numbers::<i64>(line).collect_tuple()
+            else {
+                return Err(source.error(
+                    line,
+                    "a destination range start, source range start and range length",
+                ));
+            };
+
+            let (destination_start, source_start, length) =
+                (destination_start?, source_start?, length?);
+
+            if length <= 0 {
+                return Err(source.error(line, "a positive range length"));
+            }
+
+            // Both ranges and the offset between them need to fit, the destination range isn't
+            // stored but is computed from the others when mapping
+            let source_end = source_start.checked_add(length);
+            let destination_end = destination_start.checked_add(length);
+            let offset = destination_start.checked_sub(source_start);
+
+            let (Some(source_end), Some(_), Some(offset)) = (source_end, destination_end, offset)
+            else {
+                return Err(source.error(line, "ranges that fit in 64-bit numbers"));
+            };
+
+            Ok((source_start..source_end, offset))
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(NamedMap {
+        name,
+        source: source_category,
+        destination: destination_category,
+        map: Map { rules },
+    })
+}
+
+#[derive(Debug)]
+pub struct ParseResult<'a> {
+    seeds: Vec<i64>,
+    // Maps by their name, like `seed-to-soil`
+    maps: HashMap<&'a str, NamedMap<'a>>,
+}
+
+impl<'a> ParseResult<'a> {
+    pub fn map(&self, name: &str) -> Option<&NamedMap<'a>> {
+        self.maps.get(name)
+    }
+
+    /// Follows the maps from category `from` until reaching category `to`
+    pub fn chain<'b>(&'b self, from: &'b str, to: &str) -> Result<Vec<&'b Map>, ChainError<'b>> {
+        let mut chain = Vec::new();
+        let mut category = from;
+
+        while category != to {
+            let mut next_maps = self.maps.values().filter(|map| map.source == category);
+
+            let next_map = next_maps.next().ok_or(ChainError::Missing(category))?;
+
+            if next_maps.next().is_some() {
+                return Err(ChainError::Ambiguous(category));
+            }
+
+            // A chain without circles uses every map at most once
+            if chain.len() == self.maps.len() {
+                return Err(ChainError::Cycle(category));
+            }
+
+            chain.push(&next_map.map);
+            category = next_map.destination;
+        }
+
+        Ok(chain)
+    }
+
+    /// Maps every seed goes through to reach its location, checked to exist when parsing
+    fn location_maps(&self) -> Vec<&Map> {
+        self.chain(SEED, LOCATION)
+            .expect("Maps from seed to location are checked when parsing")
+    }
+
+    /// Finds the number of `category` that corresponds to `seed`
+    pub fn find_category_number<'b>(
+        &'b self,
+        seed: i64,
+        category: &str,
+    ) -> Result<i64, ChainError<'b>> {
+        let chain = self.chain(SEED, category)?;
+
+        Ok(chain
+            .into_iter()
+            .fold(seed, |number, map| map.find_destination_number(number)))
+    }
+
+    /// Collapses all maps into one, mapping seeds straight to their location
+    pub fn seed_to_location(&self) -> Map {
+        self.location_maps()
+            .into_iter()
+            .fold(Map::default(), |chain, map| chain.compose(map))
+    }
+
+    /// Seeds as ranges, like part 2 reads them
+    fn seed_ranges(&self) -> IntervalSet<i64> {
+        self.seeds
+            .iter()
+            .copied()
+            .tuples()
+            // Checked to fit while parsing
+            .map(|(from, length)| from..(from + length))
+            .collect()
+    }
+
+    /// Finds every range of seeds that ends up in `locations`
+    pub fn find_seed_ranges(&self, locations: Range<i64>) -> IntervalSet<i64> {
+        let mut current_ranges = IntervalSet::from_iter([locations]);
+
+        for map in self.location_maps().into_iter().rev() {
+            current_ranges = current_ranges
+                .ranges()
+                .iter()
+                .flat_map(|range| map.find_source_ranges(range))
+                .collect();
+        }
+
+        current_ranges
+    }
+}
+
+/// Alternative solution to part 2, going through the locations from the lowest upward until
+/// reaching one that a seed ends up at
+///
+/// Locations are scanned in stretches that a single part of the whole chain maps onto, so every
+/// stretch only needs one reverse lookup.
+pub fn lowest_location_by_scanning(parsed: &ParseResult) -> Option<i64> {
+    let seed_ranges = parsed.seed_ranges();
+    let seed_to_location = parsed.seed_to_location();
+
+    let boundaries = seed_to_location
+        .pieces(&ALL_NUMBERS)
+        .flat_map(|(range, offset)| [range.start + offset, range.end + offset])
+        .chain([ALL_NUMBERS.start, ALL_NUMBERS.end])
+        .sorted_unstable()
+        .dedup();
+
+    for (start, end) in boundaries.tuple_windows() {
+        let locations = start..end;
+
+        // Sources move by different offsets, so each is kept apart instead of merging them
+        let lowest_location = seed_to_location
+            .find_source_ranges(&locations)
+            .flat_map(|sources| {
+                let seeds = seed_ranges.intersection(&IntervalSet::from_iter([sources]));
+
+                // Within a source, locations grow with the seeds, so its first seed is the lowest
+                seeds.ranges().first().map(|seeds| seeds.start)
+            })
+            .map(|seed| seed_to_location.find_destination_number(seed))
+            .min();
+
+        if lowest_location.is_some() {
+            return lowest_location;
+        }
+    }
+
+    None
+}
+
+fn parse_input(input: &str) -> Result<ParseResult<'_>, ParseError> {
+    let source = Source(input);
+
+    let (seeds, maps) = source.split_once(input, "\n\n")?;
+
+    let seed_numbers = source.strip_prefix(seeds, "seeds: ")?;
+
+    let seeds = source
+        .parse_numbers::<i64>(seed_numbers)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if seeds.is_empty() {
+        return Err(source.error_after(seed_numbers, "at least one seed"));
+    }
+
+    // Part 2 reads the seeds as pairs of a range start and a range length
+    if seeds.len() % 2 != 0 {
+        return Err(source.error_after(seed_numbers, "a length for the last seed range"));
+    }
+
+    let lengths = seed_numbers.split_ascii_whitespace().skip(1).step_by(2);
+
+    for (length, (&from, &length_number)) in lengths.zip(seeds.iter().tuples()) {
+        if length_number <= 0 {
+            return Err(source.error(length, "a positive seed range length"));
+        }
+
+        if from.checked_add(length_number).is_none() {
+            return Err(source.error(length, "a seed range that fits in 64-bit numbers"));
+        }
+    }
+
+    let mut named_maps = HashMap::new();
+
+    for map in maps.split("\n\n") {
+        let map = parse_map(source, map)?;
+        let name = map.name;
+
+        if named_maps.insert(name, map).is_some() {
+            return Err(source.error(name, "a map name that isn't used before"));
+        }
+    }
+
+    let parsed = ParseResult {
+        seeds,
+        maps: named_maps,
+    };
+
+    // Make sure seeds lead to exactly one location
+    if let Err(error) = parsed.chain(SEED, LOCATION) {
+        return Err(match error {
+            ChainError::Missing(category) => {
+                source.error_after(input.trim_end(), format!("a map from {category}"))
+            }
+            ChainError::Ambiguous(category) => {
+                // Point at the last of the maps going from the category
+                let last_source = parsed
+                    .maps
+                    .values()
+                    .map(|map| map.source)
+                    .filter(|&source| source == category)
+                    .max_by_key(|source| source.as_ptr())
+                    .unwrap_or(category);
+
+                source.error(last_source, "a category no other map goes from")
+            }
+            ChainError::Cycle(category) => {
+                source.error(category, format!("a category leading to {LOCATION}"))
+            }
+        });
+    }
+
+    Ok(parsed)
+}
+
+impl Solution for Day05 {
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Parsed<'a> = ParseResult<'a>;
+    type Answer = i64;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(parsed: &Self::Parsed<'_>) -> i64 {
+        let mut seeds = parsed.seeds.clone();
+
+        for map in parsed.location_maps() {
+            for seed in &mut seeds {
+                *seed = map.find_destination_number(*seed);
+            }
+        }
+
+        seeds.into_iter().min().unwrap()
+    }
+
+    fn part_2(parsed: &Self::Parsed<'_>) -> i64 {
+        let mut current_ranges = parsed.seed_ranges();
+
+        for map in parsed.location_maps() {
+            // Merging the moved ranges keeps them from splitting further at every map
+            let new_ranges = current_ranges
+                .ranges()
+                .iter()
+                .flat_map(|range| map.find_destination_ranges(range))
+                .collect();
+
+            current_ranges = new_ranges;
+        }
+
+        // Ranges are sorted, so the first one starts at the lowest location
+        current_ranges.ranges()[0].start
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        let result = Day05::solve_part_1(Day05::INPUT).unwrap();
+        assert_eq!(result, 57075758);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let result = Day05::solve_part_2(Day05::INPUT).unwrap();
+        assert_eq!(result, 31161857);
+    }
+
+    #[test]
+    fn test_example_part_1() {
+        let result = Day05::solve_part_1(include_str!("example.txt")).unwrap();
+        assert_eq!(result, 35);
+    }
+
+    #[test]
+    fn test_example_part_2() {
+        let result = Day05::solve_part_2(include_str!("example.txt")).unwrap();
+        assert_eq!(result, 46);
+    }
+
+    fn map(rules: &[(Range<i64>, i64)]) -> Map {
+        Map {
+            rules: rules.iter().cloned().collect(),
+        }
+    }
+
+    #[test]
+    fn test_compose() {
+        let first = map(&[(0..10, 100), (20..30, -5)]);
+        let second = map(&[(5..25, 1), (100..105, -100)]);
+
+        let composed = first.compose(&second);
+
+        // 0..5 is moved to 100..105 and back again, so it needs no rule
+        assert_eq!(
+            composed,
+            map(&[(5..10, 100), (10..20, 1), (20..30, -4), (100..105, -100)])
+        );
+
+        for number in -10..140 {
+            assert_eq!(
+                composed.find_destination_number(number),
+                second.find_destination_number(first.find_destination_number(number)),
+                "{number}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_seed_to_location() {
+        let parsed = Day05::parse(include_str!("example.txt")).unwrap();
+        let seed_to_location = parsed.seed_to_location();
+
+        for seed in 0..200 {
+            let location = parsed
+                .location_maps()
+                .into_iter()
+                .fold(seed, |number, map| map.find_destination_number(number));
+
+            assert_eq!(seed_to_location.find_destination_number(seed), location);
+        }
+
+        let parsed = Day05::parse(Day05::INPUT).unwrap();
+        let seed_to_location = parsed.seed_to_location();
+
+        let lowest_location = parsed
+            .seeds
+            .iter()
+            .map(|&seed| seed_to_location.find_destination_number(seed))
+            .min();
+        assert_eq!(lowest_location, Some(Day05::part_1(&parsed)));
+    }
+
+    #[test]
+    fn test_display() {
+        let map = map(&[(98..100, -48), (50..98, 2)]);
+
+        assert_eq!(map.to_string(), "50..98 +2\n98..100 -48");
+    }
+
+    #[test]
+    fn test_destination_ranges() {
+        let map = map(&[(10..20, 100), (30..40, -5)]);
+
+        let destination_ranges =
+            |range: Range<i64>| map.find_destination_ranges(&range).collect_vec();
+
+        // Straddling both rules and the gaps around them
+        assert_eq!(destination_ranges(5..35), [5..10, 110..120, 20..30, 25..30]);
+        assert_eq!(
+            destination_ranges(15..45),
+            [115..120, 20..30, 25..35, 40..45]
+        );
+
+        // Entirely inside a gap or a rule
+        assert_eq!(destination_ranges(22..28), vec![22..28]);
+        assert_eq!(destination_ranges(-8..-2), vec![-8..-2]);
+        assert_eq!(destination_ranges(12..14), vec![112..114]);
+
+        // Every number of the range ends up somewhere
+        let length = destination_ranges(0..50)
+            .iter()
+            .map(|range| range.end - range.start)
+            .sum::<i64>();
+        assert_eq!(length, 50);
+    }
+
+    #[test]
+    fn test_lowest_location_of_ranges() {
+        for input in [Day05::INPUT, include_str!("example.txt")] {
+            let parsed = Day05::parse(input).unwrap();
+            let seed_to_location = parsed.seed_to_location();
+
+            let lowest_location = parsed
+                .seeds
+                .iter()
+                .tuples()
+                .flat_map(|(&start, &length)| {
+                    let seeds = start..(start + length);
+                    seed_to_location
+                        .find_destination_ranges(&seeds)
+                        .map(|range| range.start)
+                        .collect_vec()
+                })
+                .min();
+
+            assert_eq!(lowest_location, Some(Day05::part_2(&parsed)));
+        }
+    }
+
+    #[test]
+    fn test_source_ranges() {
+        // 10..20 moves onto 110..120, and 30..40 onto 25..35, over numbers that stay in place
+        let map = map(&[(10..20, 100), (30..40, -5)]);
+
+        let source_ranges = |range: Range<i64>| map.find_source_ranges(&range).collect_vec();
+
+        assert_eq!(source_ranges(112..114), [12..14, 112..114]);
+        assert_eq!(source_ranges(12..14), Vec::<Range<i64>>::new());
+        assert_eq!(source_ranges(24..27), [24..27, 30..32]);
+        assert_eq!(source_ranges(5..15), vec![5..10]);
+
+        for range in [0..50, 100..130, -5..5] {
+            for source in source_ranges(range.clone()).into_iter().flatten() {
+                assert!(range.contains(&map.find_destination_number(source)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_find_seed_ranges() {
+        let parsed = Day05::parse(include_str!("example.txt")).unwrap();
+
+        // Seed 82 is the one ending up at the lowest location of part 2
+        let seeds = parsed.find_seed_ranges(46..47);
+        assert!(seeds.contains(82));
+
+        for seed in seeds.ranges().iter().cloned().flatten() {
+            assert_eq!(parsed.seed_to_location().find_destination_number(seed), 46);
+        }
+    }
+
+    #[test]
+    fn test_scanning_matches_part_2() {
+        // Sources next to each other that move by different offsets
+        let touching_sources = "seeds: 5 10\n\nseed-to-location map:\n10 0 10";
+
+        for input in [Day05::INPUT, include_str!("example.txt"), touching_sources] {
+            let parsed = Day05::parse(input).unwrap();
+
+            assert_eq!(
+                lowest_location_by_scanning(&parsed),
+                Some(Day05::part_2(&parsed))
+            );
+        }
+    }
+
+    #[test]
+    fn test_category_numbers() {
+        let parsed = Day05::parse(include_str!("example.txt")).unwrap();
+
+        let numbers = [
+            "soil",
+            "fertilizer",
+            "water",
+            "light",
+            "temperature",
+            "location",
+        ]
+        .map(|category| parsed.find_category_number(79, category));
+        assert_eq!(numbers, [81, 81, 81, 74, 78, 82].map(Ok));
+
+        assert_eq!(parsed.find_category_number(79, SEED), Ok(79));
+        assert_eq!(
+            parsed.find_category_number(79, "fuel"),
+            Err(ChainError::Missing(LOCATION))
+        );
+
+        let humidity_to_location = parsed.map("humidity-to-location").unwrap();
+        assert_eq!(humidity_to_location.destination, LOCATION);
+    }
+
+    #[test]
+    fn test_maps_in_any_order() {
+        let example = include_str!("example.txt").trim_end();
+        let (seeds, maps) = example.split_once("\n\n").unwrap();
+        let reversed = maps
+            .split("\n\n")
+            .collect_vec()
+            .into_iter()
+            .rev()
+            .join("\n\n");
+
+        let result = Day05::solve_part_2(&format!("{seeds}\n\n{reversed}\n")).unwrap();
+        assert_eq!(result, 46);
+    }
+
+    #[test]
+    fn test_chain_errors() {
+        let error = Day05::parse("seeds: 1 2\n\nseed-to-soil map:\n1 2 3\n").unwrap_err();
+        assert_eq!((error.line, error.column), (4, 6));
+        assert_eq!(error.expected, "a map from soil");
+
+        let ambiguous = "seeds: 1 2\n\nseed-to-location map:\n1 2 3\n\nseed-to-soil map:\n1 2 3";
+        let error = Day05::parse(ambiguous).unwrap_err();
+        assert_eq!((error.line, error.column), (6, 1));
+        assert_eq!(error.expected, "a category no other map goes from");
+
+        let cycle = "seeds: 1 2\n\nseed-to-soil map:\n1 2 3\n\nsoil-to-seed map:\n1 2 3";
+        let error = Day05::parse(cycle).unwrap_err();
+        assert_eq!((error.line, error.column), (6, 9));
+        assert_eq!(error.expected, "a category leading to location");
+
+        let duplicate = "seeds: 1 2\n\nseed-to-location map:\n1 2 3\n\nseed-to-location map:\n";
+        let error = Day05::parse(duplicate).unwrap_err();
+        assert_eq!((error.line, error.column), (6, 1));
+        assert_eq!(error.expected, "a map name that isn't used before");
+
+        let error = Day05::parse("seeds: 1 2\n\nseed-soil map:\n1 2 3").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 10));
+        assert_eq!(error.expected, "\"-to-\"");
+    }
+
+    #[test]
+    fn test_seed_errors() {
+        let error = Day05::parse("seeds: 5\n\nseed-to-location map:\n10 0 10").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 9));
+        assert_eq!(error.expected, "a length for the last seed range");
+
+        let error = Day05::parse("seeds: 5 2 8 0\n\nseed-to-location map:\n10 0 10").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 14));
+        assert_eq!(error.expected, "a positive seed range length");
+
+        let error =
+            Day05::parse("seeds: 9223372036854775000 1000\n\nseed-to-location map:\n10 0 10")
+                .unwrap_err();
+        assert_eq!((error.line, error.column), (1, 28));
+        assert_eq!(error.expected, "a seed range that fits in 64-bit numbers");
+    }
+
+    #[test]
+    fn test_overflowing_maps() {
+        for rule in [
+            "0 9223372036854775000 1000",
+            "9223372036854775000 0 1000",
+            "-9223372036854775000 9223372036854775000 1",
+        ] {
+            let input = format!("seeds: 1 2\n\nseed-to-location map:\n{rule}");
+            let error = Day05::parse(&input).unwrap_err();
+
+            assert_eq!((error.line, error.column), (4, 1), "{rule}");
+            assert_eq!(error.expected, "ranges that fit in 64-bit numbers");
+        }
+    }
+
+    #[test]
+    fn test_overlapping_seed_ranges() {
+        let parsed =
+            Day05::parse("seeds: 1 10 5 10 20 5\n\nseed-to-location map:\n100 0 3").unwrap();
+
+        assert_eq!(parsed.seed_ranges().ranges(), [1..15, 20..25]);
+        assert_eq!(Day05::part_2(&parsed), 3);
+    }
+}
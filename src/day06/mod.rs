@@ -0,0 +1,243 @@
+use crate::{
+    parse::{ParseError, Source},
+    Solution,
+};
+use itertools::Itertools;
+
+pub struct Day06;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Race {
+    // Time in milliseconds
+    time: u64,
+    // Best distance in millimeters
+    record_distance: u64,
+}
+
+impl Race {
+    fn beats_record(self, hold_time: u64) -> bool {
+        let remaining_time = (self.time - hold_time) as u128;
+        let distance = remaining_time * hold_time as u128;
+
+        distance > self.record_distance as u128
+    }
+
+    fn ways_to_beat_record(self) -> usize {
+        // Holding for h beats the record d when h * (t - h) > d, which is when h is strictly
+        // between the roots of h^2 - t * h + d, at (t ± sqrt(t^2 - 4d)) / 2
+        let time = self.time as u128;
+        let discriminant = (time * time).checked_sub(4 * self.record_distance as u128);
+
+        let Some(discriminant) = discriminant.filter(|&discriminant| discriminant > 0) else {
+            return 0;
+        };
+
+        // The integer square root is at most one off from the real root, so the rounded lower
+        // root only needs to be nudged to the first hold time that actually wins
+        let lower_root = ((time - discriminant.isqrt()) / 2) as u64;
+        let mut min_hold_time = lower_root.min(self.time / 2);
+
+        while min_hold_time > 0 && self.beats_record(min_hold_time - 1) {
+            min_hold_time -= 1;
+        }
+
+        while min_hold_time <= self.time / 2 && !self.beats_record(min_hold_time) {
+            min_hold_time += 1;
+        }
+
+        // Distances are symmetric around t / 2, so the winning hold times are too
+        let max_hold_time = self.time - min_hold_time;
+
+        (max_hold_time + 1).saturating_sub(min_hold_time) as usize
+    }
+}
+
+#[derive(Debug)]
+pub struct ParseResult {
+    races: Vec<Race>,
+    // Race read with the spaces between the numbers ignored, as part 2 does
+    joined_race: Race,
+}
+
+impl Solution for Day06 {
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Parsed<'a> = ParseResult;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let source = Source(input);
+
+        let (times, distances) = source.split_once(input, "\n")?;
+        let distances = distances.lines().next().unwrap_or(distances);
+
+        let times = source.strip_prefix(times, "Time:")?;
+        let distances = source.strip_prefix(distances, "Distance:")?;
+
+        let [time_numbers, distance_numbers] =
+            [times, distances].map(|numbers| numbers.split_ascii_whitespace().collect_vec());
+
+        if time_numbers.is_empty() {
+            return Err(source.error_after(times, "at least one race time"));
+        }
+
+        if distance_numbers.len() != time_numbers.len() {
+            let expected = format!("{} record distances", time_numbers.len());
+
+            return Err(source.error_after(distances, expected));
+        }
+
+        let races = time_numbers
+            .iter()
+            .zip(&distance_numbers)
+            .map(|(&time, &record_distance)| {
+                let time_number = source.parse::<u64>(time, "a race time")?;
+                let record_distance = source.parse::<u64>(record_distance, "a record distance")?;
+
+                if time_number == 0 {
+                    return Err(source.error(time, "a positive race time"));
+                }
+
+                Ok(Race {
+                    time: time_number,
+                    record_distance,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        // Joining the digits as they were written keeps leading zeros of the later numbers
+        let joined_time = time_numbers.concat().parse::<u64>().map_err(|_| {
+            source.error(
+                time_numbers[0],
+                "race times that fit in 64 bits when joined",
+            )
+        })?;
+        let joined_record_distance = distance_numbers.concat().parse::<u64>().map_err(|_| {
+            source.error(
+                distance_numbers[0],
+                "record distances that fit in 64 bits when joined",
+            )
+        })?;
+
+        Ok(ParseResult {
+            races,
+            joined_race: Race {
+                time: joined_time,
+                record_distance: joined_record_distance,
+            },
+        })
+    }
+
+    fn part_1(parsed: &Self::Parsed<'_>) -> usize {
+        parsed
+            .races
+            .iter()
+            .map(|race| race.ways_to_beat_record())
+            .product::<usize>()
+    }
+
+    fn part_2(parsed: &Self::Parsed<'_>) -> usize {
+        parsed.joined_race.ways_to_beat_record()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    fn ways_to_beat_record_brute_force(race: Race) -> usize {
+        let min_hold_time = (race.record_distance / race.time) + 1;
+
+        let possible_hold_times = min_hold_time..race.time;
+
+        possible_hold_times
+            .filter(|hold_time| {
+                let remaining_time = race.time - hold_time;
+                let distance = remaining_time * hold_time;
+
+                distance > race.record_distance
+            })
+            .count()
+    }
+
+    #[test]
+    fn test_part_1() {
+        let result = Day06::solve_part_1(Day06::INPUT).unwrap();
+        assert_eq!(result, 800280);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let result = Day06::solve_part_2(Day06::INPUT).unwrap();
+        assert_eq!(result, 45128024);
+    }
+
+    #[test]
+    fn test_example_part_1() {
+        let result = Day06::solve_part_1(include_str!("example.txt")).unwrap();
+        assert_eq!(result, 288);
+    }
+
+    #[test]
+    fn test_example_part_2() {
+        let result = Day06::solve_part_2(include_str!("example.txt")).unwrap();
+        assert_eq!(result, 71503);
+    }
+
+    #[test]
+    fn test_leading_zeros() {
+        // A race of 705ms with a record of 1000mm
+        let parsed = Day06::parse("Time: 7 05\nDistance: 10 00").unwrap();
+        let race = Race {
+            time: 705,
+            record_distance: 1000,
+        };
+
+        assert_eq!(Day06::part_2(&parsed), race.ways_to_beat_record());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Day06::parse("Time: 99999999999 99999999999\nDistance: 1 2").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 7));
+        assert_eq!(error.expected, "race times that fit in 64 bits when joined");
+
+        let error = Day06::parse("Time:\nDistance:").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 6));
+        assert_eq!(error.expected, "at least one race time");
+
+        let error = Day06::parse("Time: 7 15\nDistance: 9").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 12));
+        assert_eq!(error.expected, "2 record distances");
+    }
+
+    #[test]
+    fn test_matches_brute_force() {
+        let mut rng = StdRng::seed_from_u64(6);
+
+        for _ in 0..10_000 {
+            let time = rng.gen_range(1..2_000);
+            let best_distance = (time / 2) * (time - time / 2);
+
+            // Records exactly on a reachable distance are the edge cases for the roots
+            let record_distance = if rng.gen_bool(0.5) {
+                let hold_time = rng.gen_range(0..=time);
+                hold_time * (time - hold_time)
+            } else {
+                rng.gen_range(0..=best_distance + 10)
+            };
+
+            let race = Race {
+                time,
+                record_distance,
+            };
+
+            assert_eq!(
+                race.ways_to_beat_record(),
+                ways_to_beat_record_brute_force(race),
+                "{race:?}"
+            );
+        }
+    }
+}
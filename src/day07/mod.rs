@@ -0,0 +1,796 @@
+use crate::{
+    parse::{ParseError, Source},
+    Solution, Variant,
+};
+use itertools::Itertools;
+use std::{fmt, hint::black_box, rc::Rc};
+
+pub struct Day07;
+
+/// Strength of a card, its position in [`Rules::rank_order`]
+type Card = u8;
+
+type Hand = Vec<Card>;
+
+/// Kind of a hand, given by the sizes of its groups of equal cards from largest to smallest
+///
+/// Comparing the group sizes in order ranks the kinds the usual way for any hand size: five of a
+/// kind `[5]` beats four of a kind `[4, 1]`, which beats a full house `[3, 2]` and so on.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HandKind(Vec<usize>);
+
+impl HandKind {
+    pub fn group_sizes(&self) -> &[usize] {
+        &self.0
+    }
+}
+
+impl fmt::Display for HandKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self.0.as_slice() {
+            [5] => "Five of a kind",
+            [4, 1] => "Four of a kind",
+            [3, 2] => "Full house",
+            [3, 1, 1] => "Three of a kind",
+            [2, 2, 1] => "Two pair",
+            [2, 1, 1, 1] => "One pair",
+            [1, 1, 1, 1, 1] => "High card",
+            group_sizes => return write!(f, "Groups of {}", group_sizes.iter().join("+")),
+        };
+
+        f.write_str(name)
+    }
+}
+
+/// Order to compare the cards of two hands of the same kind in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TieBreak {
+    /// Compare the cards in the order they were dealt
+    DealtOrder,
+    /// Compare the strongest card of each hand first, then the next strongest and so on
+    StrongestFirst,
+}
+
+/// Rules of a variant of Camel Cards
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    /// Card labels from weakest to strongest
+    pub rank_order: String,
+    /// Labels of the cards that stand in for whichever card makes their hand strongest
+    pub wild_cards: String,
+    pub tie_break: TieBreak,
+}
+
+impl Rules {
+    pub fn part_1() -> Rules {
+        Rules {
+            rank_order: "23456789TJQKA".into(),
+            wild_cards: "".into(),
+            tie_break: TieBreak::DealtOrder,
+        }
+    }
+
+    /// Jacks become jokers, which are wild but the weakest card on their own
+    pub fn part_2() -> Rules {
+        Rules {
+            rank_order: "J23456789TQKA".into(),
+            wild_cards: "J".into(),
+            tie_break: TieBreak::DealtOrder,
+        }
+    }
+
+    fn card(&self, label: u8) -> Option<Card> {
+        let rank = self.rank_order.bytes().position(|card| card == label)?;
+
+        Some(rank as Card)
+    }
+
+    fn check_hand<'a>(
+        &self,
+        source: Source<'a>,
+        hand: &'a str,
+        known_labels: &[bool; 256],
+    ) -> Result<(), ParseError> {
+        if hand.is_empty() {
+            return Err(source.error(hand, "a hand of cards"));
+        }
+
+        match hand.bytes().position(|label| !known_labels[label as usize]) {
+            Some(index) => {
+                let labels = self.rank_order.chars().rev().collect::<String>();
+                Err(source.error(&hand[index..], format!("a card, one of {labels}")))
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Cards of a hand that was checked while parsing
+    fn hand(&self, cards: &str) -> Hand {
+        cards
+            .bytes()
+            .map(|label| self.card(label).expect("Checked while parsing"))
+            .collect()
+    }
+
+    /// Parses lines of a hand and its bid, all hands need the same number of cards
+    pub fn parse_game<'a>(&self, input: &'a str) -> Result<Vec<(&'a str, u32)>, ParseError> {
+        let source = Source(input);
+        let mut hand_size = None;
+
+        // Looked up for every card, which is faster than searching the rank order each time
+        let mut known_labels = [false; 256];
+        for label in self.rank_order.bytes() {
+            known_labels[label as usize] = true;
+        }
+
+        input
+            .lines()
+            .map(|line| {
+                let (hand, bid) = source.split_once(line, " ")?;
+
+                self.check_hand(source, hand, &known_labels)?;
+                let bid = source.parse::<u32>(bid, "a bid")?;
+
+                let hand_size = *hand_size.get_or_insert(hand.len());
+                if hand.len() != hand_size {
+                    return Err(source.error(line, format!("a hand of {hand_size} cards")));
+                }
+
+                Ok((hand, bid))
+            })
+            .collect()
+    }
+
+    fn hand_kind(&self, hand: &[Card]) -> HandKind {
+        let wild_cards = self
+            .wild_cards
+            .bytes()
+            .filter_map(|label| self.card(label))
+            .collect_vec();
+
+        get_hand_kind(hand, &wild_cards)
+    }
+
+    fn hand_with_bid<'a>(&self, cards: &'a str, bid: u32) -> HandWithBid<'a> {
+        let mut hand = self.hand(cards);
+
+        let kind = self.hand_kind(&hand);
+        let natural_kind = get_hand_kind(&hand, &[]);
+
+        if self.tie_break == TieBreak::StrongestFirst {
+            hand.sort_unstable_by(|a, b| b.cmp(a));
+        }
+
+        HandWithBid {
+            cards,
+            kind,
+            natural_kind,
+            bid,
+            tie_break: hand,
+        }
+    }
+
+    /// Ranks the hands of a game parsed with [`Rules::parse_game`] under the same rules, from
+    /// weakest to strongest
+    pub fn rank_hands<'a>(&self, game: &[(&'a str, u32)]) -> Vec<RankedHand<'a>> {
+        let hands_with_bid = game
+            .iter()
+            .map(|&(cards, bid)| self.hand_with_bid(cards, bid));
+
+        let sorted = hands_with_bid
+            .sorted_unstable_by(|a, b| (&a.kind, &a.tie_break).cmp(&(&b.kind, &b.tie_break)));
+
+        sorted
+            .enumerate()
+            .map(|(index, hand)| {
+                let rank = index as u32 + 1;
+
+                RankedHand {
+                    rank,
+                    winnings: hand.bid * rank,
+                    hand,
+                }
+            })
+            .collect()
+    }
+
+    /// Total winnings of a game parsed with [`Rules::parse_game`] under the same rules
+    ///
+    /// Sorts packed keys when the hands are small enough for them, and ranks the hands with
+    /// [`Rules::rank_hands`] otherwise.
+    pub fn total_winnings(&self, game: &[(&str, u32)]) -> u32 {
+        let hand_size = game.first().map_or(0, |(cards, _)| cards.len());
+
+        if hand_size > MAX_PACKED_HAND_SIZE || self.rank_order.len() > MAX_PACKED_RANKS {
+            return self
+                .rank_hands(game)
+                .iter()
+                .map(|ranked_hand| ranked_hand.winnings)
+                .sum();
+        }
+
+        let card_table = self.card_table();
+        let wild_mask = self.wild_mask();
+
+        let mut keys = game
+            .iter()
+            .map(|&(cards, bid)| (self.packed_key(cards, &card_table, wild_mask), bid))
+            .collect_vec();
+
+        keys.sort_unstable();
+
+        keys.iter()
+            .enumerate()
+            .map(|(index, &(_, bid))| {
+                let rank = index as u32 + 1;
+
+                bid * rank
+            })
+            .sum()
+    }
+
+    /// Card for every possible label, for looking up labels without searching the rank order
+    fn card_table(&self) -> [Card; 256] {
+        let mut table = [0; 256];
+
+        for (rank, label) in self.rank_order.bytes().enumerate() {
+            table[label as usize] = rank as Card;
+        }
+
+        table
+    }
+
+    /// Bit set of the wild cards, with a bit for each rank
+    fn wild_mask(&self) -> u16 {
+        self.wild_cards
+            .bytes()
+            .filter_map(|label| self.card(label))
+            .fold(0, |mask, card| mask | 1 << card)
+    }
+
+    /// Packs the kind of a hand and its cards in tie-break order into a single key, which sorts
+    /// the same as comparing the [`HandKind`] and then the cards, without allocating
+    ///
+    /// Only the two largest groups are needed to tell kinds of up to 5 cards apart. They take the
+    /// top bits of the key, followed by 4 bits per card.
+    fn packed_key(&self, cards: &str, card_table: &[Card; 256], wild_mask: u16) -> u32 {
+        let mut hand = [0; MAX_PACKED_HAND_SIZE];
+        let hand = &mut hand[..cards.len()];
+
+        let mut counts = [0u8; MAX_PACKED_RANKS];
+        let mut wild_amount = 0;
+
+        for (card, label) in hand.iter_mut().zip(cards.bytes()) {
+            *card = card_table[label as usize];
+
+            if wild_mask & 1 << *card != 0 {
+                wild_amount += 1;
+            } else {
+                counts[*card as usize] += 1;
+            }
+        }
+
+        let (mut largest_group, mut second_group) = (0, 0);
+
+        for count in counts {
+            if count > largest_group {
+                second_group = largest_group;
+                largest_group = count;
+            } else if count > second_group {
+                second_group = count;
+            }
+        }
+
+        // Wild cards join the largest group, like in `get_hand_kind`
+        largest_group += wild_amount;
+
+        if self.tie_break == TieBreak::StrongestFirst {
+            hand.sort_unstable_by(|a, b| b.cmp(a));
+        }
+
+        let kind = (largest_group as u32) << 3 | second_group as u32;
+
+        hand.iter().fold(kind, |key, &card| key << 4 | card as u32)
+    }
+}
+
+// Packed keys hold 4 bits per card, and 6 bits for the kind
+const MAX_PACKED_HAND_SIZE: usize = 5;
+const MAX_PACKED_RANKS: usize = 16;
+
+/// Classifies a hand as the best kind it can become by replacing its wild cards
+fn get_hand_kind(hand: &[Card], wild_cards: &[Card]) -> HandKind {
+    let is_wild = |card: &&Card| wild_cards.contains(card);
+    let wild_amount = hand.iter().filter(is_wild).count();
+
+    let mut group_sizes = hand
+        .iter()
+        .filter(|card| !is_wild(card))
+        .counts()
+        .into_values()
+        .sorted_unstable_by(|a, b| b.cmp(a))
+        .collect_vec();
+
+    // Wild cards always do best joining the largest group, since that group sorts first
+    match group_sizes.first_mut() {
+        Some(largest_group) => *largest_group += wild_amount,
+        None => group_sizes.push(wild_amount),
+    }
+
+    HandKind(group_sizes)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HandWithBid<'a> {
+    /// Card labels in the order they were dealt
+    pub cards: &'a str,
+    pub kind: HandKind,
+    /// Kind of the hand without replacing its wild cards
+    pub natural_kind: HandKind,
+    pub bid: u32,
+    // Cards in the order they are compared in when breaking ties
+    tie_break: Hand,
+}
+
+/// A hand's place in the ranking of its game, the weakest hand has rank 1
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RankedHand<'a> {
+    pub rank: u32,
+    pub hand: HandWithBid<'a>,
+    pub winnings: u32,
+}
+
+/// Renders a ranking with one hand per row, for inspecting in a spreadsheet
+pub fn ranking_to_csv(ranking: &[RankedHand]) -> String {
+    let mut csv = String::from("rank,hand,kind,bid,winnings\n");
+
+    for RankedHand {
+        rank,
+        hand,
+        winnings,
+    } in ranking
+    {
+        csv.push_str(&format!(
+            "{rank},{},{},{},{winnings}\n",
+            hand.cards, hand.kind, hand.bid
+        ));
+    }
+
+    csv
+}
+
+/// Lists how every hand of the game was classified and ranked under the rules of `part`
+pub fn explain(input: &str, part: u8) -> Result<String, ParseError> {
+    let rules = if part == 1 {
+        Rules::part_1()
+    } else {
+        Rules::part_2()
+    };
+
+    let game = rules.parse_game(input)?;
+    let ranking = rules.rank_hands(&game);
+
+    let rows = ranking
+        .iter()
+        .map(
+            |RankedHand {
+                 rank,
+                 hand,
+                 winnings,
+             }| {
+                // Strength of every card, 0 being the weakest card of the rules
+                let strengths = hand
+                    .cards
+                    .bytes()
+                    .filter_map(|label| rules.card(label))
+                    .join(" ");
+
+                let kind = if hand.kind == hand.natural_kind {
+                    hand.kind.to_string()
+                } else {
+                    format!("{} -> {}", hand.natural_kind, hand.kind)
+                };
+
+                [
+                    rank.to_string(),
+                    format!("{} [{strengths}]", hand.cards),
+                    kind,
+                    hand.bid.to_string(),
+                    winnings.to_string(),
+                ]
+            },
+        )
+        .collect_vec();
+
+    let header = ["Rank", "Cards", "Kind", "Bid", "Winnings"].map(String::from);
+
+    let widths: [usize; 5] = std::array::from_fn(|column| {
+        rows.iter()
+            .chain([&header])
+            .map(|row| row[column].chars().count())
+            .max()
+            .unwrap_or(0)
+    });
+
+    let mut output = String::new();
+
+    for row in [&header].into_iter().chain(&rows) {
+        let line = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:width$}"))
+            .join("  ");
+
+        output.push_str(line.trim_end());
+        output.push('\n');
+    }
+
+    let total_winnings = ranking
+        .iter()
+        .map(|ranked_hand| ranked_hand.winnings)
+        .sum::<u32>();
+
+    output.push_str(&format!("Total winnings: {total_winnings}"));
+
+    Ok(output)
+}
+
+impl Solution for Day07 {
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Parsed<'a> = Vec<(&'a str, u32)>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        // Both parts play with the same cards, only ranked differently
+        Rules::part_1().parse_game(input)
+    }
+
+    fn part_1(game: &Self::Parsed<'_>) -> u32 {
+        Rules::part_1().total_winnings(game)
+    }
+
+    fn part_2(game: &Self::Parsed<'_>) -> u32 {
+        Rules::part_2().total_winnings(game)
+    }
+}
+
+/// Total winnings from sorting packed keys and from ranking every hand by its kind, to compare
+/// their speed
+pub fn variants(input: &str) -> Result<Vec<Variant<'_>>, ParseError> {
+    let game = Rc::new(Day07::parse(input)?);
+    let mut variants = Vec::<Variant>::new();
+
+    for (part, rules) in [(1, Rules::part_1()), (2, Rules::part_2())] {
+        let rules = Rc::new(rules);
+
+        let (packed_rules, packed_game) = (rules.clone(), game.clone());
+        variants.push((
+            format!("part {part} packed keys"),
+            Box::new(move || {
+                black_box(packed_rules.total_winnings(black_box(&packed_game)));
+            }),
+        ));
+
+        let game = game.clone();
+        variants.push((
+            format!("part {part} hand kinds"),
+            Box::new(move || {
+                let winnings = rules
+                    .rank_hands(black_box(&game))
+                    .iter()
+                    .map(|ranked_hand| ranked_hand.winnings)
+                    .sum::<u32>();
+
+                black_box(winnings);
+            }),
+        ));
+    }
+
+    Ok(variants)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(Day07::solve_part_1(Day07::INPUT).unwrap(), 249748283);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(Day07::solve_part_2(Day07::INPUT).unwrap(), 248029057);
+    }
+
+    #[test]
+    fn test_example_part_1() {
+        assert_eq!(
+            Day07::solve_part_1(include_str!("example.txt")).unwrap(),
+            6440
+        );
+    }
+
+    #[test]
+    fn test_example_part_2() {
+        assert_eq!(
+            Day07::solve_part_2(include_str!("example.txt")).unwrap(),
+            5905
+        );
+    }
+
+    fn hand(cards: &str) -> Hand {
+        Rules::part_1().hand(cards)
+    }
+
+    #[test]
+    fn test_kind_ord() {
+        let kinds = [
+            "23456", "A23A4", "23432", "TTT98", "23332", "AA8AA", "AAAAA",
+        ]
+        .map(|cards| get_hand_kind(&hand(cards), &[]));
+
+        assert!(kinds.is_sorted());
+        assert!(!kinds.windows(2).any(|pair| pair[0] == pair[1]));
+    }
+
+    #[test]
+    fn test_other_hand_sizes() {
+        assert_eq!(get_hand_kind(&hand("KKK"), &[]), HandKind(vec![3]));
+        assert_eq!(get_hand_kind(&hand("Q2Q"), &[]), HandKind(vec![2, 1]));
+        assert_eq!(get_hand_kind(&hand("7A77AA"), &[]), HandKind(vec![3, 3]));
+        assert_eq!(
+            get_hand_kind(&hand("2233445"), &[]),
+            HandKind(vec![2, 2, 2, 1])
+        );
+
+        // Four of a kind beats two triples, which beat a full house with a single
+        assert!(get_hand_kind(&hand("77772A"), &[]) > get_hand_kind(&hand("777AAA"), &[]));
+        assert!(get_hand_kind(&hand("777AAA"), &[]) > get_hand_kind(&hand("777AA2"), &[]));
+
+        let parsed = Day07::parse("32T 1\nKKQ 2\nK2K 3").unwrap();
+        assert_eq!(Day07::part_1(&parsed), 1 + 2 * 3 + 3 * 2);
+    }
+
+    #[test]
+    fn test_wild_cards() {
+        let kind = |cards: &str, rules: &Rules| {
+            let hand = rules.hand(cards);
+
+            rules.hand_kind(&hand)
+        };
+
+        let jokers = Rules::part_2();
+        assert_eq!(kind("JJJJJ", &jokers), HandKind(vec![5]));
+        assert_eq!(kind("KTJJT", &jokers), HandKind(vec![4, 1]));
+        assert_eq!(kind("2345J", &jokers), HandKind(vec![2, 1, 1, 1]));
+        assert_eq!(kind("Q2Q2J", &jokers), HandKind(vec![3, 2]));
+        assert_eq!(kind("J2J", &jokers), HandKind(vec![3]));
+        assert_eq!(kind("KTJJT", &Rules::part_1()), HandKind(vec![2, 2, 1]));
+
+        let deuces_and_jokers = Rules {
+            wild_cards: "J2".into(),
+            ..Rules::part_2()
+        };
+        assert_eq!(kind("J2K3Q", &deuces_and_jokers), HandKind(vec![3, 1, 1]));
+        assert_eq!(kind("J2KK3Q2", &deuces_and_jokers), HandKind(vec![5, 1, 1]));
+    }
+
+    #[test]
+    fn test_rules() {
+        // With only 4 ranks, a Q beats the K and the jokers are strongest on their own
+        let rules = Rules {
+            rank_order: "KQAJ".into(),
+            wild_cards: "J".into(),
+            tie_break: TieBreak::DealtOrder,
+        };
+
+        let game = rules.parse_game("KQK 1\nQKQ 2\nAJA 3\nJJJ 4").unwrap();
+        assert_eq!(rules.total_winnings(&game), 1 + 2 * 2 + 3 * 3 + 4 * 4);
+
+        let error = rules.parse_game("KQK 1\nQ2Q 2").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.expected, "a card, one of JAQK");
+
+        // Strongest cards first, the aces of the second hand beat the king of the first
+        let rules = Rules {
+            tie_break: TieBreak::StrongestFirst,
+            ..Rules::part_1()
+        };
+
+        let game = rules.parse_game("K2234 10\n3AA45 20").unwrap();
+        assert_eq!(rules.total_winnings(&game), 10 + 20 * 2);
+
+        let game = Rules::part_1().parse_game("K2234 10\n3AA45 20").unwrap();
+        assert_eq!(Rules::part_1().total_winnings(&game), 10 * 2 + 20);
+    }
+
+    /// Tries replacing every wild card with every other card of the deck
+    fn get_hand_kind_brute_force(hand: &[Card], wild_cards: &[Card], deck: &[Card]) -> HandKind {
+        let natural_cards = deck
+            .iter()
+            .copied()
+            .filter(|card| !wild_cards.contains(card))
+            .collect_vec();
+
+        let substitutions = hand.iter().map(|card| {
+            if wild_cards.contains(card) {
+                natural_cards.clone()
+            } else {
+                vec![*card]
+            }
+        });
+
+        substitutions
+            .multi_cartesian_product()
+            .map(|hand| get_hand_kind(&hand, &[]))
+            .max()
+            .unwrap_or_else(|| HandKind(vec![]))
+    }
+
+    #[test]
+    fn test_wild_cards_match_brute_force() {
+        let mut rng = StdRng::seed_from_u64(7);
+
+        let deck = [0, 1, 2, 3, 4];
+        let wild_card_options = [&[0][..], &[0, 1], &[2, 3, 4]];
+
+        for _ in 0..2_000 {
+            let hand_size = rng.gen_range(1..=7);
+            let hand = (0..hand_size)
+                .map(|_| deck[rng.gen_range(0..deck.len())])
+                .collect_vec();
+
+            let wild_cards = wild_card_options[rng.gen_range(0..wild_card_options.len())];
+
+            assert_eq!(
+                get_hand_kind(&hand, wild_cards),
+                get_hand_kind_brute_force(&hand, wild_cards, &deck),
+                "{hand:?} with wild cards {wild_cards:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_packed_keys_match_ranking() {
+        let mut rng = StdRng::seed_from_u64(20);
+
+        let rules = [
+            Rules::part_1(),
+            Rules::part_2(),
+            Rules {
+                wild_cards: "J2".into(),
+                tie_break: TieBreak::StrongestFirst,
+                ..Rules::part_2()
+            },
+        ];
+
+        for rules in &rules {
+            let card_table = rules.card_table();
+            let wild_mask = rules.wild_mask();
+
+            let labels = rules.rank_order.as_bytes();
+
+            for _ in 0..1_000 {
+                let hand_size = rng.gen_range(1..=MAX_PACKED_HAND_SIZE);
+
+                let [first, second] = [(); 2].map(|_| {
+                    let cards = (0..hand_size)
+                        .map(|_| labels[rng.gen_range(0..4)] as char)
+                        .collect::<String>();
+
+                    let hand = rules.hand_with_bid(&cards, 0);
+                    let key = rules.packed_key(&cards, &card_table, wild_mask);
+
+                    ((hand.kind, hand.tie_break), key, cards)
+                });
+
+                assert_eq!(
+                    first.0.cmp(&second.0),
+                    first.1.cmp(&second.1),
+                    "{} and {} under {rules:?}",
+                    first.2,
+                    second.2
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_total_winnings_match_ranking() {
+        let game = Day07::parse(Day07::INPUT).unwrap();
+
+        for rules in [Rules::part_1(), Rules::part_2()] {
+            let ranked_winnings = rules
+                .rank_hands(&game)
+                .iter()
+                .map(|ranked_hand| ranked_hand.winnings)
+                .sum::<u32>();
+
+            assert_eq!(rules.total_winnings(&game), ranked_winnings);
+        }
+    }
+
+    #[test]
+    fn test_ranking() {
+        let game = Day07::parse(include_str!("example.txt")).unwrap();
+        let ranking = Rules::part_2().rank_hands(&game);
+
+        let order = ranking
+            .iter()
+            .map(|ranked_hand| (ranked_hand.rank, ranked_hand.hand.cards))
+            .collect_vec();
+        assert_eq!(
+            order,
+            [
+                (1, "32T3K"),
+                (2, "KK677"),
+                (3, "T55J5"),
+                (4, "QQQJA"),
+                (5, "KTJJT")
+            ]
+        );
+
+        assert_eq!(
+            ranking_to_csv(&ranking),
+            "rank,hand,kind,bid,winnings\n\
+             1,32T3K,One pair,765,765\n\
+             2,KK677,Two pair,28,56\n\
+             3,T55J5,Four of a kind,684,2052\n\
+             4,QQQJA,Four of a kind,483,1932\n\
+             5,KTJJT,Four of a kind,220,1100\n"
+        );
+    }
+
+    #[test]
+    fn test_variants() {
+        let variants = variants(include_str!("example.txt")).unwrap();
+
+        let names = variants.iter().map(|(name, _)| name.as_str()).collect_vec();
+        assert_eq!(
+            names,
+            [
+                "part 1 packed keys",
+                "part 1 hand kinds",
+                "part 2 packed keys",
+                "part 2 hand kinds"
+            ]
+        );
+
+        for (_, variant) in &variants {
+            variant();
+        }
+    }
+
+    #[test]
+    fn test_explain() {
+        let explanation = explain(include_str!("example.txt"), 2).unwrap();
+
+        assert_eq!(
+            explanation,
+            "Rank  Cards                  Kind                               Bid  Winnings\n\
+             1     32T3K [2 1 9 2 11]     One pair                           765  765\n\
+             2     KK677 [11 11 5 6 6]    Two pair                           28   56\n\
+             3     T55J5 [9 4 4 0 4]      Three of a kind -> Four of a kind  684  2052\n\
+             4     QQQJA [10 10 10 0 12]  Three of a kind -> Four of a kind  483  1932\n\
+             5     KTJJT [11 9 0 0 9]     Two pair -> Four of a kind         220  1100\n\
+             Total winnings: 5905"
+        );
+    }
+
+    #[test]
+    fn test_kind_display() {
+        assert_eq!(HandKind(vec![3, 2]).to_string(), "Full house");
+        assert_eq!(HandKind(vec![3, 3]).to_string(), "Groups of 3+3");
+    }
+
+    #[test]
+    fn test_mixed_hand_sizes() {
+        let error = Day07::parse("32T3K 765\nT55J 684").unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.expected, "a hand of 5 cards");
+    }
+}
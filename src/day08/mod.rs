@@ -0,0 +1,252 @@
+use crate::{
+    parse::{ParseError, Source},
+    Solution,
+};
+use num::integer::lcm;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+pub struct Day08;
+
+/// Steps it takes to reach the end, unless there's no way to get there
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Steps {
+    Taken(u64),
+    // Description of the missing start node
+    NoStart(&'static str),
+    // Description of the missing end node
+    NoEnd(&'static str),
+    // Description of the end node that the instructions never lead to
+    Unreachable(&'static str),
+}
+
+impl fmt::Display for Steps {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Steps::Taken(steps) => write!(f, "{steps}"),
+            Steps::NoStart(start) => write!(f, "no node {start} to start from"),
+            Steps::NoEnd(end) => write!(f, "no node {end} to end at"),
+            Steps::Unreachable(end) => write!(f, "the instructions never lead to a node {end}"),
+        }
+    }
+}
+
+enum Instruction {
+    Left,
+    Right,
+}
+
+struct Node<'a> {
+    left: &'a str,
+    right: &'a str,
+}
+
+pub struct Network<'a> {
+    instructions: Vec<Instruction>,
+    nodes: HashMap<&'a str, Node<'a>>,
+}
+
+impl<'a> Network<'a> {
+    /// Steps from `from` until reaching a node that `to` accepts, or `None` if the walk goes
+    /// around in circles without reaching one
+    fn amount_of_steps(&self, from: &'a str, to: impl Fn(&'a str) -> bool) -> Option<u64> {
+        let mut steps: u64 = 0;
+        let mut position = from;
+
+        // Every circle passes the start of the instructions, so the walk repeats once it starts
+        // them over at a node it started them at before
+        let mut round_starts = HashSet::new();
+
+        while round_starts.insert(position) {
+            for instruction in &self.instructions {
+                let node = self.nodes.get(position).unwrap();
+
+                let next_position = match instruction {
+                    Instruction::Left => node.left,
+                    Instruction::Right => node.right,
+                };
+
+                position = next_position;
+                steps += 1;
+
+                if to(position) {
+                    return Some(steps);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+fn parse_network(input: &str) -> Result<Network<'_>, ParseError> {
+    let source = Source(input);
+
+    let (instructions, nodes) = source.split_once(input, "\n\n")?;
+
+    if instructions.is_empty() {
+        return Err(source.error(instructions, "at least one instruction"));
+    }
+
+    let instructions = instructions
+        .char_indices()
+        .map(|(index, instruction)| match instruction {
+            'L' => Ok(Instruction::Left),
+            'R' => Ok(Instruction::Right),
+            _ => Err(source.error(&instructions[index..], "an instruction, L or R")),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let nodes = nodes
+        .lines()
+        .map(|line| {
+            let (from, targets) = source.split_once(line, " = ")?;
+            let targets = source.strip_prefix(targets, "(")?;
+            let targets = source.strip_suffix(targets, ")")?;
+            let (left, right) = source.split_once(targets, ", ")?;
+
+            Ok((from, Node { left, right }))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let targets = nodes
+        .iter()
+        .flat_map(|(_, node)| [node.left, node.right])
+        .collect::<Vec<_>>();
+    let nodes = nodes.into_iter().collect::<HashMap<_, _>>();
+
+    let unknown_node = targets
+        .into_iter()
+        .find(|target| !nodes.contains_key(target));
+
+    if let Some(unknown_node) = unknown_node {
+        return Err(source.error(unknown_node, "a node that is defined in the network"));
+    }
+
+    Ok(Network {
+        instructions,
+        nodes,
+    })
+}
+
+impl Solution for Day08 {
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Parsed<'a> = Network<'a>;
+    type Answer = Steps;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_network(input)
+    }
+
+    fn part_1(network: &Self::Parsed<'_>) -> Steps {
+        // Inputs made for part 2 only, like its example, don't need to have this node
+        if !network.nodes.contains_key("AAA") {
+            return Steps::NoStart("AAA");
+        }
+
+        if !network.nodes.contains_key("ZZZ") {
+            return Steps::NoEnd("ZZZ");
+        }
+
+        network
+            .amount_of_steps("AAA", |node| node == "ZZZ")
+            .map_or(Steps::Unreachable("ZZZ"), Steps::Taken)
+    }
+
+    fn part_2(network: &Self::Parsed<'_>) -> Steps {
+        let start_positions = network
+            .nodes
+            .keys()
+            .copied()
+            .filter(|node| node.ends_with('A'))
+            .collect::<Vec<_>>();
+
+        if start_positions.is_empty() {
+            return Steps::NoStart("ending with A");
+        }
+
+        if !network.nodes.keys().any(|node| node.ends_with('Z')) {
+            return Steps::NoEnd("ending with Z");
+        }
+
+        let steps_for_each_cycle = start_positions
+            .into_iter()
+            .map(|from| network.amount_of_steps(from, |node| node.ends_with('Z')))
+            .collect::<Option<Vec<_>>>();
+
+        match steps_for_each_cycle {
+            Some(steps_for_each_cycle) => {
+                Steps::Taken(steps_for_each_cycle.into_iter().fold(1, lcm))
+            }
+            None => Steps::Unreachable("ending with Z"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let result = Day08::solve_part_1(Day08::INPUT).unwrap();
+        assert_eq!(result, Steps::Taken(16579));
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = Day08::solve_part_2(Day08::INPUT).unwrap();
+        assert_eq!(result, Steps::Taken(12927600769609));
+    }
+
+    #[test]
+    fn test_example_part1() {
+        let result = Day08::solve_part_1(include_str!("example_1.txt")).unwrap();
+        assert_eq!(result, Steps::Taken(2));
+
+        let result = Day08::solve_part_1(include_str!("example_2.txt")).unwrap();
+        assert_eq!(result, Steps::Taken(6));
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let result = Day08::solve_part_2(include_str!("example_3.txt")).unwrap();
+        assert_eq!(result, Steps::Taken(6));
+    }
+
+    #[test]
+    fn test_missing_start() {
+        let result = Day08::solve_part_1(include_str!("example_3.txt")).unwrap();
+        assert_eq!(result, Steps::NoStart("AAA"));
+        assert_eq!(result.to_string(), "no node AAA to start from");
+
+        let result = Day08::solve_part_2("L\n\nBBB = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap();
+        assert_eq!(result, Steps::NoStart("ending with A"));
+    }
+
+    #[test]
+    fn test_unreachable_end() {
+        let circle = "L\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)";
+        assert_eq!(Day08::solve_part_1(circle), Ok(Steps::NoEnd("ZZZ")));
+        assert_eq!(
+            Day08::solve_part_2(circle),
+            Ok(Steps::NoEnd("ending with Z"))
+        );
+
+        // ZZZ exists, but the left turns never lead there
+        let circle = "L\n\nAAA = (BBB, ZZZ)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)";
+        let result = Day08::solve_part_1(circle).unwrap();
+        assert_eq!(result, Steps::Unreachable("ZZZ"));
+        assert_eq!(
+            result.to_string(),
+            "the instructions never lead to a node ZZZ"
+        );
+        assert_eq!(
+            Day08::solve_part_2(circle),
+            Ok(Steps::Unreachable("ending with Z"))
+        );
+    }
+}
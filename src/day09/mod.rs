@@ -0,0 +1,111 @@
+use crate::{
+    parse::{ParseError, Source},
+    Solution,
+};
+use itertools::Itertools;
+use std::rc::Rc;
+
+pub struct Day09;
+
+fn parse_history_line<'a>(source: Source<'a>, line: &'a str) -> Result<Vec<i32>, ParseError> {
+    source.parse_numbers::<i32>(line).collect()
+}
+
+fn parse_input(input: &str) -> impl Iterator<Item = Result<Vec<i32>, ParseError>> + '_ {
+    let source = Source(input);
+
+    input
+        .lines()
+        .map(move |line| parse_history_line(source, line))
+}
+
+fn calculate_history_diffs(history: Vec<i32>) -> Vec<Rc<Vec<i32>>> {
+    let history = Rc::new(history);
+
+    let mut all_differences = vec![history.clone()];
+    let mut history_differences = history;
+
+    while history_differences.iter().any(|&value| value != 0) {
+        history_differences = history_differences
+            .iter()
+            .copied()
+            .tuple_windows()
+            .map(|(left, right)| right - left)
+            .collect_vec()
+            .into();
+
+        all_differences.push(history_differences.clone());
+    }
+
+    all_differences
+}
+
+impl Solution for Day09 {
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Parsed<'a> = Vec<Vec<i32>>;
+    type Answer = i32;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input).collect()
+    }
+
+    fn part_1(histories: &Self::Parsed<'_>) -> i32 {
+        let predicted_values = histories.iter().map(|history| {
+            let all_differences = calculate_history_diffs(history.clone());
+
+            let predicted_value = all_differences
+                .into_iter()
+                .map(|values| values.last().copied().unwrap_or(0))
+                .sum::<i32>();
+
+            predicted_value
+        });
+
+        predicted_values.sum()
+    }
+
+    fn part_2(histories: &Self::Parsed<'_>) -> i32 {
+        let predicted_values = histories.iter().map(|history| {
+            let all_differences = calculate_history_diffs(history.clone());
+
+            let predicted_value = all_differences
+                .into_iter()
+                .map(|values| values.first().copied().unwrap_or(0))
+                .rfold(0, |acc, item| item - acc);
+
+            predicted_value
+        });
+
+        predicted_values.sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let result = Day09::solve_part_1(Day09::INPUT).unwrap();
+        assert_eq!(result, 2098530125);
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = Day09::solve_part_2(Day09::INPUT).unwrap();
+        assert_eq!(result, 1016);
+    }
+
+    #[test]
+    fn test_example_part1() {
+        let result = Day09::solve_part_1(include_str!("example.txt")).unwrap();
+        assert_eq!(result, 114);
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let result = Day09::solve_part_2(include_str!("example.txt")).unwrap();
+        assert_eq!(result, 2);
+    }
+}
@@ -0,0 +1,426 @@
+use crate::{
+    grid::Grid,
+    parse::{ParseError, Source},
+    polygon, Solution,
+};
+use vek::Vec2;
+
+pub struct Day10;
+
+#[derive(Debug)]
+pub struct ParseResult {
+    map: Grid<char>,
+    main_loop: MainLoop,
+}
+
+impl ParseResult {
+    pub fn main_loop(&self) -> &MainLoop {
+        &self.main_loop
+    }
+}
+
+/// The loop of pipes going through the start tile
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MainLoop {
+    // Positions in walking order, starting at the start tile
+    positions: Vec<Vec2<usize>>,
+}
+
+impl MainLoop {
+    pub fn positions(&self) -> &[Vec2<usize>] {
+        &self.positions
+    }
+
+    /// Number of steps it takes to walk the whole loop
+    pub fn length(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// Number of steps along the loop from the start tile to the point farthest from it
+    pub fn farthest_distance(&self) -> usize {
+        self.positions.len() / 2
+    }
+
+    /// Positions farthest from the start tile, two of them if the loop has an odd length
+    pub fn farthest_points(&self) -> Vec<Vec2<usize>> {
+        let distance = self.farthest_distance();
+
+        if self.positions.len().is_multiple_of(2) {
+            vec![self.positions[distance]]
+        } else {
+            vec![self.positions[distance], self.positions[distance + 1]]
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<ParseResult, ParseError> {
+    let source = Source(input);
+    let mut start_point = None;
+
+    let map = Grid::parse(input, |position, char| match char {
+        'S' if start_point.is_some() => Err("only a single start tile"),
+        'S' => {
+            start_point = Some(position);
+            Ok('S')
+        }
+        '.' => Ok('.'),
+        'L' => Ok('╚'),
+        'J' => Ok('╝'),
+        '7' => Ok('╗'),
+        'F' => Ok('╔'),
+        '|' => Ok('║'),
+        '-' => Ok('═'),
+        _ => Err("a pipe, ground or start tile"),
+    })?;
+
+    let start_point = start_point.ok_or_else(|| source.error_after(input, "a start tile"))?;
+
+    let (main_loop, start_shape) = find_main_loop(&map, start_point).ok_or_else(|| {
+        let start_tile = &input[input.find('S').unwrap_or_default()..];
+        source.error(start_tile, "a start tile on a closed loop of pipes")
+    })?;
+
+    // Replace the start tile with the pipe it stands in for
+    let mut map = map;
+    map[start_point] = start_shape;
+
+    Ok(ParseResult { map, main_loop })
+}
+
+const RIGHT: Vec2<i32> = Vec2::new(1, 0);
+const LEFT: Vec2<i32> = Vec2::new(-1, 0);
+const DOWN: Vec2<i32> = Vec2::new(0, 1);
+const UP: Vec2<i32> = Vec2::new(0, -1);
+
+const PIPES: [char; 6] = ['═', '║', '╚', '╝', '╗', '╔'];
+
+/// Directions to the two tiles a pipe connects to
+fn pipe_ends(pipe: char) -> Option<[Vec2<i32>; 2]> {
+    match pipe {
+        '═' => Some([LEFT, RIGHT]),
+        '║' => Some([UP, DOWN]),
+        '╚' => Some([UP, RIGHT]),
+        '╝' => Some([UP, LEFT]),
+        '╗' => Some([LEFT, DOWN]),
+        '╔' => Some([RIGHT, DOWN]),
+        _ => None,
+    }
+}
+
+fn pipe_with_ends(first: Vec2<i32>, second: Vec2<i32>) -> Option<char> {
+    PIPES.into_iter().find(|&pipe| {
+        pipe_ends(pipe).is_some_and(|ends| ends == [first, second] || ends == [second, first])
+    })
+}
+
+/// Follows the pipes leaving the start tile in `direction`, returning `None` if they end up
+/// anywhere but back at the start tile
+fn walk_loop(map: &Grid<char>, start_point: Vec2<usize>, direction: Vec2<i32>) -> Option<MainLoop> {
+    let mut positions = vec![start_point];
+    let mut previous_position = start_point;
+    let mut current_position = map.offset(start_point, direction)?;
+
+    while current_position != start_point {
+        let came_from = previous_position.as_::<i32>() - current_position.as_::<i32>();
+
+        // The pipe has to connect back to the previous one
+        let next_direction = match pipe_ends(map[current_position])? {
+            [end, next] | [next, end] if end == came_from => next,
+            _ => return None,
+        };
+
+        positions.push(current_position);
+        previous_position = current_position;
+        current_position = map.offset(current_position, next_direction)?;
+    }
+
+    Some(MainLoop { positions })
+}
+
+/// Tries leaving the start tile in every direction until the pipes lead back to it, returning
+/// the loop and the pipe the start tile has to be to connect both of its ends
+fn find_main_loop(map: &Grid<char>, start_point: Vec2<usize>) -> Option<(MainLoop, char)> {
+    [RIGHT, LEFT, DOWN, UP].into_iter().find_map(|direction| {
+        let main_loop = walk_loop(map, start_point, direction)?;
+
+        let last_position = *main_loop.positions.last()?;
+        let returning_direction = last_position.as_::<i32>() - start_point.as_::<i32>();
+
+        let start_shape = pipe_with_ends(direction, returning_direction)?;
+
+        Some((main_loop, start_shape))
+    })
+}
+
+/// Copy of the map with only the pipes of the main loop, everything else is ground
+fn find_loop_map(map: &Grid<char>, main_loop: &MainLoop) -> Grid<char> {
+    let mut loop_map = Grid::from_elem(map.size(), '.');
+
+    for &position in main_loop.positions() {
+        loop_map[position] = map[position];
+    }
+
+    loop_map
+}
+
+/// Finds the tiles inside the loop, by counting how often each column crosses it from the top
+fn find_enclosed_tiles(loop_map: &Grid<char>) -> Grid<bool> {
+    let size = loop_map.size();
+    let mut enclosed_tiles = Grid::from_elem(size, false);
+
+    for x in 0..size.x {
+        let mut pipe_count = 0;
+
+        #[derive(PartialEq)]
+        enum Turn {
+            Left,
+            Right,
+        }
+
+        // Default value, will always be changed before being used
+        let mut previous_turn = Turn::Left;
+
+        for y in 0..size.y {
+            let position = Vec2::new(x, y);
+
+            match loop_map[position] {
+                '═' => {
+                    pipe_count += 1;
+                }
+
+                '╗' => {
+                    previous_turn = Turn::Left;
+                }
+
+                '╔' => previous_turn = Turn::Right,
+
+                '╝' => {
+                    if previous_turn == Turn::Left {
+                        pipe_count += 2;
+                    } else {
+                        pipe_count += 1;
+                    }
+                }
+
+                '╚' => {
+                    if previous_turn == Turn::Right {
+                        pipe_count += 2;
+                    } else {
+                        pipe_count += 1;
+                    }
+                }
+
+                '.' if pipe_count % 2 != 0 => {
+                    enclosed_tiles[position] = true;
+                }
+
+                _ => {}
+            }
+        }
+    }
+
+    enclosed_tiles
+}
+
+/// Alternative solution to part 2, treating the loop as a polygon through the centers of its
+/// tiles: Pick's theorem gives the tiles strictly inside it from its area and loop length
+pub fn count_enclosed_by_area(parsed: &ParseResult) -> usize {
+    let ParseResult { map, main_loop, .. } = parsed;
+
+    let vertices = main_loop
+        .positions()
+        .iter()
+        .filter(|&&position| matches!(map[position], '╔' | '╗' | '╚' | '╝'))
+        .map(|position| position.as_::<i64>())
+        .collect::<Vec<_>>();
+
+    polygon::interior_points(&vertices) as usize
+}
+
+const LOOP_COLOR: &str = "\x1b[1;33m";
+const ENCLOSED_COLOR: &str = "\x1b[1;32m";
+const OUTSIDE_COLOR: &str = "\x1b[2;34m";
+const RESET_COLOR: &str = "\x1b[0m";
+
+/// Renders the map for a terminal, with the main loop highlighted and every other tile marked
+/// as enclosed (`I`) or outside (`O`) the way part 2 counts them
+pub fn visualize(input: &str) -> Result<String, ParseError> {
+    let ParseResult { map, main_loop, .. } = parse_input(input)?;
+
+    let loop_map = find_loop_map(&map, &main_loop);
+    let enclosed_tiles = find_enclosed_tiles(&loop_map);
+
+    let mut output = String::new();
+
+    for (position, &pipe) in loop_map.iter() {
+        if position.x == 0 && position.y > 0 {
+            output.push('\n');
+        }
+
+        let (color, tile) = match pipe {
+            '.' if enclosed_tiles[position] => (ENCLOSED_COLOR, 'I'),
+            '.' => (OUTSIDE_COLOR, 'O'),
+            pipe => (LOOP_COLOR, pipe),
+        };
+
+        output.push_str(&format!("{color}{tile}{RESET_COLOR}"));
+    }
+
+    Ok(output)
+}
+
+impl Solution for Day10 {
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Parsed<'a> = ParseResult;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(parsed: &Self::Parsed<'_>) -> usize {
+        parsed.main_loop.farthest_distance()
+    }
+
+    fn part_2(parsed: &Self::Parsed<'_>) -> usize {
+        let ParseResult { map, main_loop, .. } = parsed;
+
+        let loop_map = find_loop_map(map, main_loop);
+        let enclosed_tiles = find_enclosed_tiles(&loop_map);
+
+        enclosed_tiles
+            .iter()
+            .filter(|&(_, &is_enclosed)| is_enclosed)
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let result = Day10::solve_part_1(Day10::INPUT).unwrap();
+        assert_eq!(result, 6956);
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = Day10::solve_part_2(Day10::INPUT).unwrap();
+        assert_eq!(result, 455);
+    }
+
+    #[test]
+    fn test_example_part1() {
+        let result = Day10::solve_part_1(include_str!("example_1.txt")).unwrap();
+        assert_eq!(result, 4);
+
+        let result = Day10::solve_part_1(include_str!("example_2.txt")).unwrap();
+        assert_eq!(result, 8);
+    }
+
+    #[test]
+    fn test_main_loop() {
+        let parsed = Day10::parse(include_str!("example_1.txt")).unwrap();
+        let main_loop = parsed.main_loop();
+
+        assert_eq!(main_loop.length(), 8);
+        assert_eq!(main_loop.positions()[0], Vec2::new(1, 1));
+        assert_eq!(main_loop.farthest_points(), [Vec2::new(3, 3)]);
+
+        let parsed = Day10::parse(include_str!("example_2.txt")).unwrap();
+        let main_loop = parsed.main_loop();
+
+        assert_eq!(main_loop.length(), 16);
+        assert_eq!(main_loop.farthest_distance(), 8);
+        assert_eq!(main_loop.farthest_points(), [Vec2::new(4, 2)]);
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let result = Day10::solve_part_2(include_str!("example_3.txt")).unwrap();
+        assert_eq!(result, 4);
+
+        let result = Day10::solve_part_2(include_str!("example_4.txt")).unwrap();
+        assert_eq!(result, 8);
+
+        let result = Day10::solve_part_2(include_str!("example_5.txt")).unwrap();
+        assert_eq!(result, 10);
+    }
+
+    #[test]
+    fn test_area_matches_crossing_count() {
+        let inputs = [
+            Day10::INPUT,
+            include_str!("example_1.txt"),
+            include_str!("example_2.txt"),
+            include_str!("example_3.txt"),
+            include_str!("example_4.txt"),
+            include_str!("example_5.txt"),
+        ];
+
+        for input in inputs {
+            let parsed = Day10::parse(input).unwrap();
+
+            assert_eq!(count_enclosed_by_area(&parsed), Day10::part_2(&parsed));
+        }
+    }
+
+    #[test]
+    fn test_visualize() {
+        let output = visualize(".....\n.F-7.\n.S.|.\n.L-J.\n.....").unwrap();
+
+        let output = [LOOP_COLOR, ENCLOSED_COLOR, OUTSIDE_COLOR, RESET_COLOR]
+            .into_iter()
+            .fold(output, |output, color| output.replace(color, ""));
+
+        assert_eq!(output, "OOOOO\nO╔═╗O\nO║I║O\nO╚═╝O\nOOOOO");
+    }
+
+    #[test]
+    fn test_start_shape() {
+        let cases = [
+            ("S-7\n|.|\nL-J", '╔'),
+            ("F-S\n|.|\nL-J", '╗'),
+            ("F-7\n|.|\nS-J", '╚'),
+            ("F-7\n|.|\nL-S", '╝'),
+            ("FS7\n|.|\nL-J", '═'),
+            ("F-7\nS.|\nL-J", '║'),
+        ];
+
+        for (input, shape) in cases {
+            let parsed = Day10::parse(input).unwrap();
+            let start_point = parsed.main_loop.positions()[0];
+            assert_eq!(parsed.map[start_point], shape, "{input}");
+
+            assert_eq!(Day10::part_1(&parsed), 4, "{input}");
+            assert_eq!(Day10::part_2(&parsed), 1, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_start_next_to_dead_end() {
+        // The pipe to the right of the start tile connects to it, but leads nowhere
+        let parsed = Day10::parse(".....\nF-S-.\n|.|..\nL-J..").unwrap();
+
+        assert_eq!(parsed.map[Vec2::new(2, 1)], '╗');
+        assert_eq!(Day10::part_1(&parsed), 4);
+        assert_eq!(Day10::part_2(&parsed), 1);
+    }
+
+    #[test]
+    fn test_start_without_loop() {
+        let inputs = ["...\n.S-\n...", ".F7\n-S.\n.LJ", "S-7\n|.|\nL-."];
+
+        for input in inputs {
+            let error = Day10::parse(input).unwrap_err();
+
+            assert_eq!(error.expected, "a start tile on a closed loop of pipes");
+        }
+
+        let error = Day10::parse("...\n.S-\n...").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+    }
+}
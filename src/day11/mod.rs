@@ -0,0 +1,115 @@
+use crate::{grid::Grid, parse::ParseError, Solution};
+use itertools::Itertools;
+use std::collections::HashSet;
+use vek::Vec2;
+
+pub struct Day11;
+
+fn find_galaxies(map: &Grid<bool>, expansion: usize) -> Vec<Vec2<usize>> {
+    // Find empty columns and rows
+    let empty_columns = map
+        .columns()
+        .enumerate()
+        .filter_map(|(x, mut tiles)| tiles.all(|&is_galaxy| !is_galaxy).then_some(x))
+        .collect::<HashSet<_>>();
+
+    let empty_rows = map
+        .rows()
+        .enumerate()
+        .filter_map(|(y, tiles)| tiles.iter().all(|&is_galaxy| !is_galaxy).then_some(y))
+        .collect::<HashSet<_>>();
+
+    // Find galaxy positions after expansion
+    let mut galaxies = Vec::new();
+    let mut y_expansion = 0;
+
+    for (y, row) in map.rows().enumerate() {
+        if empty_rows.contains(&y) {
+            y_expansion += expansion;
+        }
+
+        let mut x_expansion = 0;
+
+        for (x, &is_galaxy) in row.iter().enumerate() {
+            if empty_columns.contains(&x) {
+                x_expansion += expansion;
+            }
+
+            if is_galaxy {
+                galaxies.push(Vec2::new(x + x_expansion, y + y_expansion));
+            }
+        }
+    }
+
+    galaxies
+}
+
+fn find_distances(galaxies: Vec<Vec2<usize>>) -> usize {
+    let mut total_distance = 0;
+
+    for (from, to) in galaxies.into_iter().tuple_combinations() {
+        let distance = from.zip(to).map(|(from, to)| from.abs_diff(to)).sum();
+
+        total_distance += distance;
+    }
+
+    total_distance
+}
+
+impl Solution for Day11 {
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Parsed<'a> = Grid<bool>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Grid::parse(input, |_, char| match char {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err("a galaxy or empty space"),
+        })
+    }
+
+    fn part_1(map: &Self::Parsed<'_>) -> usize {
+        let galaxies = find_galaxies(map, 1);
+
+        find_distances(galaxies)
+    }
+
+    fn part_2(map: &Self::Parsed<'_>) -> usize {
+        let galaxies = find_galaxies(map, 999_999);
+
+        find_distances(galaxies)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let result = Day11::solve_part_1(Day11::INPUT).unwrap();
+        assert_eq!(result, 9605127);
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = Day11::solve_part_2(Day11::INPUT).unwrap();
+        assert_eq!(result, 458191688761);
+    }
+
+    #[test]
+    fn test_example_part1() {
+        let result = Day11::solve_part_1(include_str!("example.txt")).unwrap();
+        assert_eq!(result, 374);
+    }
+
+    #[test]
+    fn test_example_expansion() {
+        let map = Day11::parse(include_str!("example.txt")).unwrap();
+
+        assert_eq!(find_distances(find_galaxies(&map, 9)), 1030);
+        assert_eq!(find_distances(find_galaxies(&map, 99)), 8410);
+    }
+}
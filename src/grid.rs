@@ -0,0 +1,270 @@
+use crate::parse::{ParseError, Source};
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+use vek::Vec2;
+
+// Offsets to the right, left, bottom and top neighbors
+const NEIGHBOR_OFFSETS_4: [Vec2<i32>; 4] = [
+    Vec2::new(1, 0),
+    Vec2::new(-1, 0),
+    Vec2::new(0, 1),
+    Vec2::new(0, -1),
+];
+
+// Offsets to all surrounding neighbors, row by row
+const NEIGHBOR_OFFSETS_8: [Vec2<i32>; 8] = [
+    Vec2::new(-1, -1),
+    Vec2::new(0, -1),
+    Vec2::new(1, -1),
+    Vec2::new(-1, 0),
+    Vec2::new(1, 0),
+    Vec2::new(-1, 1),
+    Vec2::new(0, 1),
+    Vec2::new(1, 1),
+];
+
+/// Rectangular grid of cells stored row by row, indexed by `(x, y)` positions with `y` going down
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    size: Vec2<usize>,
+}
+
+impl<T> Grid<T> {
+    pub fn from_elem(size: Vec2<usize>, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![value; size.product()],
+            size,
+        }
+    }
+
+    /// Parses a grid with one cell per character, `parse_cell` returns what it expected on failure
+    pub fn parse(
+        input: &str,
+        mut parse_cell: impl FnMut(Vec2<usize>, char) -> Result<T, &'static str>,
+    ) -> Result<Grid<T>, ParseError> {
+        let source = Source(input);
+
+        let width = input.lines().next().map_or(0, |line| line.chars().count());
+        let mut cells = Vec::new();
+        let mut height = 0;
+
+        for (y, line) in input.lines().enumerate() {
+            if line.chars().count() != width {
+                return Err(source.error(line, format!("a row of {width} tiles")));
+            }
+
+            for (x, (index, char)) in line.char_indices().enumerate() {
+                let cell = parse_cell(Vec2::new(x, y), char)
+                    .map_err(|expected| source.error(&line[index..], expected))?;
+
+                cells.push(cell);
+            }
+
+            height += 1;
+        }
+
+        Ok(Grid {
+            cells,
+            size: Vec2::new(width, height),
+        })
+    }
+
+    pub fn size(&self) -> Vec2<usize> {
+        self.size
+    }
+
+    pub fn width(&self) -> usize {
+        self.size.x
+    }
+
+    pub fn height(&self) -> usize {
+        self.size.y
+    }
+
+    /// Converts a possibly negative or out of bounds position into one inside the grid
+    fn checked_position<I: TryInto<usize>>(&self, position: Vec2<I>) -> Option<Vec2<usize>> {
+        let x = position.x.try_into().ok()?;
+        let y = position.y.try_into().ok()?;
+
+        (x < self.size.x && y < self.size.y).then_some(Vec2::new(x, y))
+    }
+
+    fn index_of(&self, position: Vec2<usize>) -> usize {
+        position.y * self.size.x + position.x
+    }
+
+    pub fn get<I: TryInto<usize>>(&self, position: Vec2<I>) -> Option<&T> {
+        let position = self.checked_position(position)?;
+
+        Some(&self.cells[self.index_of(position)])
+    }
+
+    pub fn get_mut<I: TryInto<usize>>(&mut self, position: Vec2<I>) -> Option<&mut T> {
+        let position = self.checked_position(position)?;
+        let index = self.index_of(position);
+
+        Some(&mut self.cells[index])
+    }
+
+    /// Moves `position` by `offset`, if the result is still inside the grid
+    pub fn offset(&self, position: Vec2<usize>, offset: Vec2<i32>) -> Option<Vec2<usize>> {
+        let position = position.as_::<i64>() + offset.as_::<i64>();
+
+        self.checked_position(position)
+    }
+
+    /// Positions directly to the right, left, bottom and top of `position` that are inside the grid
+    pub fn neighbors_4(&self, position: Vec2<usize>) -> impl Iterator<Item = Vec2<usize>> + '_ {
+        NEIGHBOR_OFFSETS_4
+            .into_iter()
+            .filter_map(move |offset| self.offset(position, offset))
+    }
+
+    /// Positions surrounding `position`, including diagonals, that are inside the grid
+    pub fn neighbors_8(&self, position: Vec2<usize>) -> impl Iterator<Item = Vec2<usize>> + '_ {
+        NEIGHBOR_OFFSETS_8
+            .into_iter()
+            .filter_map(move |offset| self.offset(position, offset))
+    }
+
+    /// All positions in the grid, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Vec2<usize>> {
+        let size = self.size;
+
+        (0..size.y).flat_map(move |y| (0..size.x).map(move |x| Vec2::new(x, y)))
+    }
+
+    /// All cells together with their position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Vec2<usize>, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // Chunks panics on a size of 0, which an empty grid would have
+        self.cells.chunks(self.size.x.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.size.x).map(move |x| self.cells.iter().skip(x).step_by(self.size.x))
+    }
+
+    pub fn map<U>(&self, mapper: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(mapper).collect(),
+            size: self.size,
+        }
+    }
+}
+
+impl<T> Index<Vec2<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Vec2<usize>) -> &T {
+        self.get(position).expect("Position out of bounds")
+    }
+}
+
+impl<T> IndexMut<Vec2<usize>> for Grid<T> {
+    fn index_mut(&mut self, position: Vec2<usize>) -> &mut T {
+        self.get_mut(position).expect("Position out of bounds")
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_digits(input: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(input, |_, char| char.to_digit(10).ok_or("a digit"))
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid = parse_digits("123\n456\n").unwrap();
+
+        assert_eq!(grid.size(), Vec2::new(3, 2));
+        assert_eq!(grid[Vec2::new(2, 0)], 3);
+        assert_eq!(grid[Vec2::new(0, 1)], 4);
+        assert_eq!(grid.to_string(), "123\n456");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_digits("123\n4x6").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.expected, "a digit");
+
+        let error = parse_digits("123\n45").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.expected, "a row of 3 tiles");
+    }
+
+    #[test]
+    fn test_get() {
+        let grid = parse_digits("12\n34").unwrap();
+
+        assert_eq!(grid.get(Vec2::new(1, 1)), Some(&4));
+        assert_eq!(grid.get(Vec2::new(-1, 0)), None);
+        assert_eq!(grid.get(Vec2::new(2usize, 0)), None);
+        assert_eq!(
+            grid.offset(Vec2::new(1, 0), Vec2::new(-1, 1)),
+            Some(Vec2::new(0, 1))
+        );
+        assert_eq!(grid.offset(Vec2::new(1, 0), Vec2::new(0, -1)), None);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = parse_digits("123\n456\n789").unwrap();
+
+        let corner = grid
+            .neighbors_4(Vec2::new(0, 0))
+            .map(|position| grid[position])
+            .collect::<Vec<_>>();
+        assert_eq!(corner, [2, 4]);
+
+        let center = grid
+            .neighbors_8(Vec2::new(1, 1))
+            .map(|position| grid[position])
+            .collect::<Vec<_>>();
+        assert_eq!(center, [1, 2, 3, 4, 6, 7, 8, 9]);
+
+        assert_eq!(grid.neighbors_8(Vec2::new(2, 2)).count(), 3);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = parse_digits("123\n456").unwrap();
+
+        let rows = grid.rows().map(|row| row.to_vec()).collect::<Vec<_>>();
+        assert_eq!(rows, [vec![1, 2, 3], vec![4, 5, 6]]);
+
+        let columns = grid
+            .columns()
+            .map(|column| column.copied().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(columns, [vec![1, 4], vec![2, 5], vec![3, 6]]);
+    }
+}
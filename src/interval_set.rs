@@ -0,0 +1,217 @@
+use crate::RangeExt;
+use itertools::Itertools;
+use std::{
+    iter::Sum,
+    ops::{Range, Sub},
+};
+
+/// Set of values stored as sorted ranges, where overlapping and adjacent ranges are merged
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: Ord + Copy> IntervalSet<T> {
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet { ranges: Vec::new() }
+    }
+
+    /// Sorts `ranges` and merges the ones that overlap or touch, dropping empty ones
+    fn normalize(mut ranges: Vec<Range<T>>) -> IntervalSet<T> {
+        ranges.retain(|range| !range.is_empty());
+        ranges.sort_unstable_by_key(|range| range.start);
+
+        let ranges = ranges
+            .into_iter()
+            .coalesce(|previous, next| {
+                if next.start <= previous.end {
+                    Ok(previous.start..previous.end.max(next.end))
+                } else {
+                    Err((previous, next))
+                }
+            })
+            .collect();
+
+        IntervalSet { ranges }
+    }
+
+    /// Disjoint ranges of the set, from lowest to highest
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        // Index of the first range starting after `value`, so only the one before can contain it
+        let index = self.ranges.partition_point(|range| range.start <= value);
+
+        index > 0 && value < self.ranges[index - 1].end
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        let mut ranges = std::mem::take(&mut self.ranges);
+        ranges.push(range);
+
+        *self = IntervalSet::normalize(ranges);
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        self.ranges.iter().chain(&other.ranges).cloned().collect()
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut ranges = Vec::new();
+        let (mut left, mut right) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+
+        while let (Some(left_range), Some(right_range)) = (left.peek(), right.peek()) {
+            ranges.extend(left_range.overlap(right_range));
+
+            // The range ending first can't overlap anything else of the other set
+            if left_range.end < right_range.end {
+                left.next();
+            } else {
+                right.next();
+            }
+        }
+
+        IntervalSet { ranges }
+    }
+
+    /// Values of this set that aren't in `other`
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let (Some(first), Some(last)) = (self.ranges.first(), self.ranges.last()) else {
+            return IntervalSet::new();
+        };
+
+        self.intersection(&other.complement(first.start..last.end))
+    }
+
+    /// Values within `bounds` that aren't in this set
+    pub fn complement(&self, bounds: Range<T>) -> IntervalSet<T> {
+        let mut ranges = Vec::new();
+        let mut start = bounds.start;
+
+        for range in self
+            .ranges
+            .iter()
+            .filter_map(|range| range.overlap(&bounds))
+        {
+            if start < range.start {
+                ranges.push(start..range.start);
+            }
+
+            start = range.end;
+        }
+
+        if start < bounds.end {
+            ranges.push(start..bounds.end);
+        }
+
+        IntervalSet { ranges }
+    }
+
+    /// Number of values in the set
+    pub fn len(&self) -> T
+    where
+        T: Sub<Output = T> + Sum,
+    {
+        self.ranges
+            .iter()
+            .map(|range| range.end - range.start)
+            .sum()
+    }
+}
+
+impl<T: Ord + Copy> Default for IntervalSet<T> {
+    fn default() -> IntervalSet<T> {
+        IntervalSet::new()
+    }
+}
+
+impl<T: Ord + Copy> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(ranges: I) -> IntervalSet<T> {
+        IntervalSet::normalize(ranges.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[Range<i64>]) -> IntervalSet<i64> {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn test_merging() {
+        // Overlapping, adjacent, unsorted and empty ranges
+        let set = set(&[10..20, 0..5, 15..25, 5..7, 30..30, 40..45]);
+
+        assert_eq!(set.ranges(), [0..7, 10..25, 40..45]);
+        assert_eq!(set.len(), 27);
+
+        let mut inserted = set.clone();
+        inserted.insert(7..10);
+        assert_eq!(inserted.ranges(), [0..25, 40..45]);
+    }
+
+    #[test]
+    fn test_contains() {
+        let set = set(&[0..5, 10..20]);
+
+        assert!(set.contains(0));
+        assert!(set.contains(19));
+        assert!(!set.contains(5));
+        assert!(!set.contains(-1));
+        assert!(!set.contains(20));
+        assert!(!IntervalSet::new().contains(0));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let left = set(&[0..10, 20..30]);
+        let right = set(&[5..25, 28..40]);
+
+        assert_eq!(left.union(&right).ranges(), vec![0..40]);
+        assert_eq!(left.intersection(&right), set(&[5..10, 20..25, 28..30]));
+        assert_eq!(left.difference(&right), set(&[0..5, 25..28]));
+        assert_eq!(right.difference(&left), set(&[10..20, 30..40]));
+        assert_eq!(left.difference(&left), IntervalSet::new());
+    }
+
+    #[test]
+    fn test_complement() {
+        let set = set(&[0..10, 20..30]);
+
+        assert_eq!(set.complement(-5..35).ranges(), [-5..0, 10..20, 30..35]);
+        assert_eq!(set.complement(5..25).ranges(), vec![10..20]);
+        assert_eq!(set.complement(12..18).ranges(), vec![12..18]);
+        assert!(set.complement(0..10).is_empty());
+    }
+
+    #[test]
+    fn test_matches_values() {
+        let left = set(&[-3..4, 8..9, 12..20]);
+        let right = set(&[0..10, 15..16, 19..25]);
+
+        for value in -5..30 {
+            let (in_left, in_right) = (left.contains(value), right.contains(value));
+
+            assert_eq!(left.union(&right).contains(value), in_left || in_right);
+            assert_eq!(
+                left.intersection(&right).contains(value),
+                in_left && in_right
+            );
+            assert_eq!(
+                left.difference(&right).contains(value),
+                in_left && !in_right
+            );
+        }
+    }
+}
@@ -1,14 +1,69 @@
 use std::{
     cmp::{max, min},
+    fmt::{Debug, Display},
     ops::Range,
 };
 
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod grid;
+pub mod interval_set;
+pub mod parse;
+pub mod polygon;
+
+use parse::ParseError;
+
 pub trait RangeExt<T> {
+    /// Values in both ranges, which is an empty or inverted range if they don't overlap
     fn intersect(&self, other: &Range<T>) -> Range<T>;
+
+    /// Values in both ranges, if there are any
+    fn overlap(&self, other: &Range<T>) -> Option<Range<T>>;
 }
 
 impl<T: Ord + Copy> RangeExt<T> for Range<T> {
     fn intersect(&self, other: &Range<T>) -> Range<T> {
         max(self.start, other.start)..min(self.end, other.end)
     }
+
+    fn overlap(&self, other: &Range<T>) -> Option<Range<T>> {
+        let intersection = self.intersect(other);
+
+        (!intersection.is_empty()).then_some(intersection)
+    }
+}
+
+/// Named implementation of a part, called repeatedly to time it against other implementations
+pub type Variant<'a> = (String, Box<dyn Fn() + 'a>);
+
+/// A day's puzzle, split into parsing the input and solving each part from the parsed input
+pub trait Solution {
+    /// Bundled puzzle input
+    const INPUT: &'static str;
+
+    type Parsed<'a>;
+    type Answer: Display + Debug + PartialEq;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError>;
+
+    fn part_1(parsed: &Self::Parsed<'_>) -> Self::Answer;
+
+    fn part_2(parsed: &Self::Parsed<'_>) -> Self::Answer;
+
+    fn solve_part_1(input: &str) -> Result<Self::Answer, ParseError> {
+        Self::parse(input).map(|parsed| Self::part_1(&parsed))
+    }
+
+    fn solve_part_2(input: &str) -> Result<Self::Answer, ParseError> {
+        Self::parse(input).map(|parsed| Self::part_2(&parsed))
+    }
 }
@@ -0,0 +1,145 @@
+use std::{error::Error, fmt, str::FromStr};
+
+/// Error from parsing a puzzle input, pointing at the position where parsing failed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    // Line number, starting at 1
+    pub line: usize,
+    // Column number in characters, starting at 1
+    pub column: usize,
+    // Description of what should have been at this position
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Renders the error together with the offending line of `input` and a marker under the column
+    pub fn diagnostic(&self, input: &str) -> String {
+        let source_line = input.lines().nth(self.line - 1).unwrap_or_default();
+        let line_number = self.line.to_string();
+        let padding = " ".repeat(line_number.len());
+        let marker_offset = " ".repeat(self.column - 1);
+
+        format!("{self}\n{padding} |\n{line_number} | {source_line}\n{padding} | {marker_offset}^")
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// The full text being parsed, used to locate sub-slices of it when reporting errors
+#[derive(Debug, Clone, Copy)]
+pub struct Source<'a>(pub &'a str);
+
+impl<'a> Source<'a> {
+    /// Creates an error pointing at the start of `fragment`, which must be a slice of the source
+    pub fn error(self, fragment: &str, expected: impl Into<String>) -> ParseError {
+        let offset = (fragment.as_ptr() as usize).wrapping_sub(self.0.as_ptr() as usize);
+        debug_assert!(offset <= self.0.len(), "fragment is not part of the source");
+        let offset = offset.min(self.0.len());
+
+        let before = &self.0[..offset];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            expected: expected.into(),
+        }
+    }
+
+    /// Creates an error pointing just past the end of `fragment`
+    pub fn error_after(self, fragment: &str, expected: impl Into<String>) -> ParseError {
+        self.error(&fragment[fragment.len()..], expected)
+    }
+
+    pub fn split_once(
+        self,
+        fragment: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        // Searching for a single character is much faster than setting up a substring search
+        let split = match delimiter.as_bytes() {
+            &[byte] => fragment.split_once(byte as char),
+            _ => fragment.split_once(delimiter),
+        };
+
+        split.ok_or_else(|| self.error_after(fragment, format!("{delimiter:?}")))
+    }
+
+    pub fn strip_prefix(self, fragment: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        fragment
+            .strip_prefix(prefix)
+            .ok_or_else(|| self.error(fragment, format!("{prefix:?}")))
+    }
+
+    pub fn strip_suffix(self, fragment: &'a str, suffix: &str) -> Result<&'a str, ParseError> {
+        fragment
+            .strip_suffix(suffix)
+            .ok_or_else(|| self.error_after(fragment, format!("{suffix:?}")))
+    }
+
+    pub fn parse<T: FromStr>(self, fragment: &'a str, expected: &str) -> Result<T, ParseError> {
+        fragment
+            .parse::<T>()
+            .map_err(|_| self.error(fragment, expected))
+    }
+
+    /// Parses every whitespace separated number in `fragment`
+    pub fn parse_numbers<T: FromStr>(
+        self,
+        fragment: &'a str,
+    ) -> impl Iterator<Item = Result<T, ParseError>> + 'a {
+        fragment
+            .split_ascii_whitespace()
+            .map(move |number| self.parse(number, "a number"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_position() {
+        let input = "seeds: 1 2\n\nfoo 12 x4";
+        let source = Source(input);
+        let fragment = &input[19..21];
+
+        let error = source.error(fragment, "a number");
+
+        assert_eq!(error.line, 3);
+        assert_eq!(error.column, 8);
+        assert_eq!(error.to_string(), "line 3, column 8: expected a number");
+    }
+
+    #[test]
+    fn test_error_after() {
+        let input = "Game 1";
+        let source = Source(input);
+
+        let error = source.split_once(input, ": ").unwrap_err();
+
+        assert_eq!((error.line, error.column), (1, 7));
+        assert_eq!(error.expected, "\": \"");
+    }
+
+    #[test]
+    fn test_diagnostic() {
+        let input = "32T3K 765\nT55X5 684";
+        let error = Source(input).error(&input[13..], "a card");
+
+        assert_eq!(
+            error.diagnostic(input),
+            "line 2, column 4: expected a card\n  |\n2 | T55X5 684\n  |    ^"
+        );
+    }
+}
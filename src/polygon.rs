@@ -0,0 +1,83 @@
+use itertools::Itertools;
+use num::integer::gcd;
+use vek::Vec2;
+
+/// Twice the area of a simple polygon, using the shoelace formula
+///
+/// The vertices may be in either winding order, and the polygon is closed implicitly.
+pub fn double_area(vertices: &[Vec2<i64>]) -> u64 {
+    let signed_area = vertices
+        .iter()
+        .circular_tuple_windows()
+        .map(|(from, to)| from.x * to.y - to.x * from.y)
+        .sum::<i64>();
+
+    signed_area.unsigned_abs()
+}
+
+/// Number of lattice points on the edges of a polygon
+pub fn boundary_points(vertices: &[Vec2<i64>]) -> u64 {
+    vertices
+        .iter()
+        .circular_tuple_windows()
+        .map(|(from, to)| gcd(to.x - from.x, to.y - from.y).unsigned_abs())
+        .sum()
+}
+
+/// Number of lattice points strictly inside a polygon whose vertices are lattice points, using
+/// Pick's theorem
+pub fn interior_points(vertices: &[Vec2<i64>]) -> u64 {
+    if vertices.len() < 3 {
+        return 0;
+    }
+
+    // Pick's theorem: A = I + B / 2 - 1, so 2I = 2A - B + 2
+    let double_interior = double_area(vertices) as i64 + 2 - boundary_points(vertices) as i64;
+
+    // Polygons without area, like collinear vertices, can give a negative count
+    (double_interior / 2).max(0) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vertices(points: &[(i64, i64)]) -> Vec<Vec2<i64>> {
+        points.iter().map(|&point| point.into()).collect()
+    }
+
+    #[test]
+    fn test_rectangle() {
+        let rectangle = vertices(&[(0, 0), (4, 0), (4, 3), (0, 3)]);
+
+        assert_eq!(double_area(&rectangle), 24);
+        assert_eq!(boundary_points(&rectangle), 14);
+        assert_eq!(interior_points(&rectangle), 6);
+    }
+
+    #[test]
+    fn test_winding_order() {
+        let clockwise = vertices(&[(0, 0), (0, 3), (4, 3), (4, 0)]);
+
+        assert_eq!(double_area(&clockwise), 24);
+        assert_eq!(interior_points(&clockwise), 6);
+    }
+
+    #[test]
+    fn test_triangle() {
+        // Diagonal edge from (4, 0) to (0, 4) passes through 3 lattice points between its ends
+        let triangle = vertices(&[(0, 0), (4, 0), (0, 4)]);
+
+        assert_eq!(double_area(&triangle), 16);
+        assert_eq!(boundary_points(&triangle), 12);
+        assert_eq!(interior_points(&triangle), 3);
+    }
+
+    #[test]
+    fn test_degenerate() {
+        assert_eq!(interior_points(&[]), 0);
+        assert_eq!(interior_points(&vertices(&[(0, 0), (5, 0)])), 0);
+        assert_eq!(interior_points(&vertices(&[(0, 0), (1, 0), (2, 0)])), 0);
+        assert_eq!(interior_points(&vertices(&[(0, 0), (3, 3), (0, 0)])), 0);
+    }
+}